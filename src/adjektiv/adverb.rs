@@ -0,0 +1,68 @@
+// Adverbien des Positivs, die nicht nach der Regel ihrer Deklinationsklasse (-e, -iter, -er)
+// gebildet werden. Die unregelmäßigen Steigerungen (bene, male, ...) stehen in unregelmaessig.rs
+#[derive(Clone, Copy)]
+pub enum Adverbbildung {
+    Ablativ,                    // subitus -> subito
+    Akkusativ,                  // facilis -> facile
+    Unregelmäßig(&'static str), // audax -> audacter
+    Keines,                     // unus, vetus
+}
+
+// Nach dem Nominativ Singular Maskulinum
+static AUSNAHMEN: [(&str, Adverbbildung); 36] = [
+    ("subitus", Adverbbildung::Ablativ),
+    ("tutus", Adverbbildung::Ablativ),
+    ("primus", Adverbbildung::Ablativ),
+    ("continuus", Adverbbildung::Ablativ),
+    ("perpetuus", Adverbbildung::Ablativ),
+    ("necessarius", Adverbbildung::Ablativ),
+    ("falsus", Adverbbildung::Ablativ),
+    ("meritus", Adverbbildung::Ablativ),
+    ("improvisus", Adverbbildung::Ablativ),
+    ("manifestus", Adverbbildung::Ablativ),
+    ("citus", Adverbbildung::Ablativ),
+    ("serus", Adverbbildung::Ablativ),
+    ("creber", Adverbbildung::Ablativ),
+    ("facilis", Adverbbildung::Akkusativ),
+    ("ceterus", Adverbbildung::Akkusativ),
+    ("solus", Adverbbildung::Akkusativ),
+    ("recens", Adverbbildung::Akkusativ),
+    ("audax", Adverbbildung::Unregelmäßig("audacter")),
+    ("difficilis", Adverbbildung::Unregelmäßig("difficulter")),
+    ("violentus", Adverbbildung::Unregelmäßig("violenter")),
+    ("sollers", Adverbbildung::Unregelmäßig("sollerter")),
+    ("alius", Adverbbildung::Unregelmäßig("aliter")),
+    ("unus", Adverbbildung::Keines),
+    ("totus", Adverbbildung::Keines),
+    ("nullus", Adverbbildung::Keines),
+    ("ullus", Adverbbildung::Keines),
+    ("alter", Adverbbildung::Keines),
+    ("uter", Adverbbildung::Keines),
+    ("neuter", Adverbbildung::Keines),
+    ("vetus", Adverbbildung::Keines),
+    ("dives", Adverbbildung::Keines),
+    ("pauper", Adverbbildung::Keines),
+    ("princeps", Adverbbildung::Keines),
+    ("particeps", Adverbbildung::Keines),
+    ("compos", Adverbbildung::Keines),
+    ("superstes", Adverbbildung::Keines),
+];
+
+// Adverbien, neben denen weitere Formen zulässig sind: primo, primum
+static VARIANTEN: [(&str, &[&str]); 1] = [("primo", &["primum"])];
+
+impl Adverbbildung {
+    pub fn finden(nominativ_singular_maskulinum: &str) -> Option<Self> {
+        AUSNAHMEN
+            .iter()
+            .find(|(nominativ, _)| *nominativ == nominativ_singular_maskulinum)
+            .map(|&(_, bildung)| bildung)
+    }
+}
+
+pub fn weitere_varianten(adverb: &str) -> &'static [&'static str] {
+    VARIANTEN
+        .iter()
+        .find(|(bevorzugt, _)| *bevorzugt == adverb)
+        .map_or(&[], |&(_, varianten)| varianten)
+}
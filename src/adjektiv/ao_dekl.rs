@@ -1,14 +1,19 @@
 use crate::grammatik::{test_form, Genus, Kasus, Numerus, Steigerung};
 
 use super::{
-    komperativ::KomperativDeklination, superlativ::SuperlativDeklination, Deklination,
-    WörterbuchEintrag,
+    komperativ::KomperativDeklination, parse_muster, periphrastisch::PeriphrastischeDeklination,
+    superlativ::SuperlativDeklination, unregelmaessig::UnregelmäßigeSteigerung, Ablehnungsgrund,
+    Deklination, Deklinationsklasse, Klassifikation, WörterbuchEintrag,
 };
 
-pub const ADVERB_ENDUNG: &'static str = "e";
+pub const ADVERB_ENDUNG: &str = "e";
 
-pub fn get_endung(genus: Genus, numerus: Numerus, kasus: Kasus) -> &'static str {
-    match genus {
+// Stämme von Adjektiven ohne Komperativ und Superlativ: die Possessivpronomen und ceterus, medius,
+// mortuus
+const OHNE_STEIGERUNG: [&str; 8] = ["me", "tu", "su", "nostr", "vestr", "ceter", "medi", "mortu"];
+
+pub fn get_endung(genus: Genus, numerus: Numerus, kasus: Kasus) -> Option<&'static str> {
+    Some(match genus {
         Genus::Maskulinum => match numerus {
             Numerus::Singular => match kasus {
                 Kasus::Nominativ => "us",
@@ -16,6 +21,7 @@ pub fn get_endung(genus: Genus, numerus: Numerus, kasus: Kasus) -> &'static str
                 Kasus::Dativ => "o",
                 Kasus::Akkusativ => "um",
                 Kasus::Ablativ => "o",
+                Kasus::Lokativ => "i",
                 Kasus::Vokativ => "e",
             },
             Numerus::Plural => match kasus {
@@ -24,6 +30,7 @@ pub fn get_endung(genus: Genus, numerus: Numerus, kasus: Kasus) -> &'static str
                 Kasus::Dativ => "is",
                 Kasus::Akkusativ => "os",
                 Kasus::Ablativ => "is",
+                Kasus::Lokativ => "is",
             },
         },
         Genus::Femininum => match numerus {
@@ -33,6 +40,7 @@ pub fn get_endung(genus: Genus, numerus: Numerus, kasus: Kasus) -> &'static str
                 Kasus::Dativ => "ae",
                 Kasus::Akkusativ => "am",
                 Kasus::Ablativ => "a",
+                Kasus::Lokativ => "ae",
             },
             Numerus::Plural => match kasus {
                 Kasus::Nominativ | Kasus::Vokativ => "ae",
@@ -40,6 +48,7 @@ pub fn get_endung(genus: Genus, numerus: Numerus, kasus: Kasus) -> &'static str
                 Kasus::Dativ => "is",
                 Kasus::Akkusativ => "as",
                 Kasus::Ablativ => "is",
+                Kasus::Lokativ => "is",
             },
         },
         Genus::Neutrum => match numerus {
@@ -49,6 +58,7 @@ pub fn get_endung(genus: Genus, numerus: Numerus, kasus: Kasus) -> &'static str
                 Kasus::Dativ => "o",
                 Kasus::Akkusativ => "um",
                 Kasus::Ablativ => "o",
+                Kasus::Lokativ => "i",
             },
             Numerus::Plural => match kasus {
                 Kasus::Nominativ | Kasus::Vokativ => "a",
@@ -56,9 +66,10 @@ pub fn get_endung(genus: Genus, numerus: Numerus, kasus: Kasus) -> &'static str
                 Kasus::Dativ => "is",
                 Kasus::Akkusativ => "a",
                 Kasus::Ablativ => "is",
+                Kasus::Lokativ => "is",
             },
         },
-    }
+    })
 }
 
 #[derive(Clone)]
@@ -69,136 +80,167 @@ pub struct AODeklination<'a> {
 
 impl<'a> AODeklination<'a> {
     // bonus
-    fn parse_a_um_single(eintrag: &WörterbuchEintrag<'a>) -> Option<Self> {
+    fn parse_a_um_single(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, Ablehnungsgrund> {
         let &WörterbuchEintrag {
             erste_form,
             zweite_form: None,
             dritte_form: None,
+            ..
         } = eintrag else {
-            return None;
+            return Err(Ablehnungsgrund::FormenAnzahlPasstNicht);
         };
 
-        let stamm = if erste_form.ends_with("us") {
-            &erste_form[..erste_form.len() - 2]
-        } else {
-            return None;
-        };
+        let stamm = erste_form
+            .strip_suffix("us")
+            .ok_or(Ablehnungsgrund::ErsteFormPasstNicht)?;
 
-        Some(Self {
+        Ok(Self {
             nominativ_singular_maskulinum: None,
             stamm,
         })
     }
 
     // bonus, bona, bonum
-    fn parse_a_um_long(eintrag: &WörterbuchEintrag<'a>) -> Option<Self> {
+    fn parse_a_um_long(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, Ablehnungsgrund> {
         let &WörterbuchEintrag {
             erste_form,
             zweite_form: Some(zweite_form),
             dritte_form: Some(dritte_form),
+            ..
         } = eintrag else {
-            return None;
+            return Err(Ablehnungsgrund::FormenAnzahlPasstNicht);
         };
 
-        let stamm = if erste_form.ends_with("us") {
-            &erste_form[..erste_form.len() - 2]
-        } else {
-            return None;
-        };
+        let stamm = erste_form
+            .strip_suffix("us")
+            .ok_or(Ablehnungsgrund::ErsteFormPasstNicht)?;
 
-        if !test_form(zweite_form, stamm, "a") || !test_form(dritte_form, stamm, "um") {
-            return None;
+        if !test_form(zweite_form, stamm, "a") {
+            return Err(Ablehnungsgrund::ZweiteFormPasstNicht);
+        }
+        if !test_form(dritte_form, stamm, "um") {
+            return Err(Ablehnungsgrund::DritteFormPasstNicht);
         }
 
-        Some(Self {
+        Ok(Self {
             nominativ_singular_maskulinum: None,
             stamm,
         })
     }
 
     // bonus, a, um
-    fn parse_a_um_short(eintrag: &WörterbuchEintrag<'a>) -> Option<Self> {
+    fn parse_a_um_short(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, Ablehnungsgrund> {
         let &WörterbuchEintrag {
             erste_form,
-            zweite_form: Some("a"),
-            dritte_form: Some("um"),
+            zweite_form: Some(zweite_form),
+            dritte_form: Some(dritte_form),
+            ..
         } = eintrag else {
-            return None;
+            return Err(Ablehnungsgrund::FormenAnzahlPasstNicht);
         };
 
-        let stamm = if erste_form.ends_with("us") {
-            &erste_form[..erste_form.len() - 2]
-        } else {
-            return None;
-        };
+        let stamm = erste_form
+            .strip_suffix("us")
+            .ok_or(Ablehnungsgrund::ErsteFormPasstNicht)?;
 
-        Some(Self {
+        if zweite_form != "a" {
+            return Err(Ablehnungsgrund::ZweiteFormPasstNicht);
+        }
+        if dritte_form != "um" {
+            return Err(Ablehnungsgrund::DritteFormPasstNicht);
+        }
+
+        Ok(Self {
             nominativ_singular_maskulinum: None,
             stamm,
         })
     }
 
     // pulcher, pulchra, pulchrum
-    fn parse_er(eintrag: &WörterbuchEintrag<'a>) -> Option<Self> {
+    fn parse_er(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, Ablehnungsgrund> {
         let &WörterbuchEintrag {
             erste_form,
             zweite_form: Some(zweite_form),
             dritte_form: Some(dritte_form),
+            ..
         } = eintrag else {
-            return None;
+            return Err(Ablehnungsgrund::FormenAnzahlPasstNicht);
         };
 
         if !erste_form.ends_with("er") {
-            return None;
+            return Err(Ablehnungsgrund::ErsteFormPasstNicht);
         }
 
-        let stamm = if zweite_form.ends_with("a") {
-            &zweite_form[..zweite_form.len() - 1]
-        } else {
-            return None;
-        };
+        let stamm = zweite_form
+            .strip_suffix('a')
+            .ok_or(Ablehnungsgrund::ZweiteFormPasstNicht)?;
 
         if !test_form(dritte_form, stamm, "um") {
-            return None;
+            return Err(Ablehnungsgrund::DritteFormPasstNicht);
         }
 
-        return Some(Self {
+        Ok(Self {
             nominativ_singular_maskulinum: Some(erste_form),
             stamm,
-        });
+        })
     }
 
-    pub fn parse(eintrag: &WörterbuchEintrag<'a>) -> Option<Self> {
-        if let result @ Some(_) = Self::parse_a_um_single(eintrag) {
-            result
-        } else if let result @ Some(_) = Self::parse_a_um_short(eintrag) {
-            result
-        } else if let result @ Some(_) = Self::parse_a_um_long(eintrag) {
-            result
-        } else if let result @ Some(_) = Self::parse_er(eintrag) {
-            result
-        } else {
-            None
-        }
+    pub fn parse(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, Ablehnungsgrund> {
+        parse_muster(
+            eintrag,
+            &[
+                Self::parse_a_um_single,
+                Self::parse_a_um_short,
+                Self::parse_a_um_long,
+                Self::parse_er,
+            ],
+        )
     }
 
-    pub fn deklinieren(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> String {
+    pub fn deklinieren(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> Option<String> {
         if let Some(nominativ_singular_maskulinum) = self.nominativ_singular_maskulinum {
             if let (Kasus::Nominativ, Numerus::Singular, Genus::Maskulinum) =
                 (kasus, numerus, genus)
             {
-                return String::from(nominativ_singular_maskulinum);
+                return Some(String::from(nominativ_singular_maskulinum));
             }
         }
 
-        let endung = get_endung(genus, numerus, kasus);
+        let endung = get_endung(genus, numerus, kasus)?;
         let mut form = String::with_capacity(self.stamm.len() + endung.len());
         form.push_str(self.stamm);
         form.push_str(endung);
-        form
+        Some(form)
+    }
+
+    pub fn from_stamm(stamm: &'a str) -> Self {
+        Self {
+            nominativ_singular_maskulinum: None,
+            stamm,
+        }
+    }
+
+    pub fn get_stamm(&self) -> &'a str {
+        self.stamm
+    }
+
+    // idoneus, arduus, necessarius; nicht aber antiquus
+    fn ist_vokalstamm(&self) -> bool {
+        matches!(self.stamm.chars().last(), Some('e' | 'i' | 'u')) && !self.stamm.ends_with("qu")
+    }
+
+    pub fn klassifikation(&self) -> Klassifikation<'a> {
+        Klassifikation {
+            klasse: Deklinationsklasse::AODeklination,
+            stamm: self.stamm,
+        }
     }
 
     pub fn adverb(&self) -> String {
+        if let Some(steigerung) = UnregelmäßigeSteigerung::finden(self.stamm) {
+            return String::from(steigerung.adverb);
+        }
+
         let mut adverb = String::with_capacity(self.stamm.len() + ADVERB_ENDUNG.len());
         adverb.push_str(self.stamm);
         adverb.push_str(ADVERB_ENDUNG);
@@ -206,14 +248,44 @@ impl<'a> AODeklination<'a> {
     }
 
     pub(super) fn steigern(&self, steigerung: Steigerung) -> Option<Deklination<'a>> {
+        if OHNE_STEIGERUNG.contains(&self.stamm) {
+            return match steigerung {
+                Steigerung::Positiv => Some(Deklination::Ao(self.clone())),
+                Steigerung::Komperativ | Steigerung::Superlativ => None,
+            };
+        }
+
+        if let Some(unregelmäßig) = UnregelmäßigeSteigerung::finden(self.stamm) {
+            return Some(match steigerung {
+                Steigerung::Positiv => Deklination::Ao(self.clone()),
+                Steigerung::Komperativ => Deklination::Komperativ(
+                    KomperativDeklination::new_unregelmäßig(self.stamm, &unregelmäßig.komperativ),
+                ),
+                Steigerung::Superlativ => Deklination::Superlativ(
+                    SuperlativDeklination::new_unregelmäßig(self.stamm, &unregelmäßig.superlativ),
+                ),
+            });
+        }
+
+        if self.ist_vokalstamm() {
+            return Some(match steigerung {
+                Steigerung::Positiv => Deklination::Ao(self.clone()),
+                _ => Deklination::Periphrastisch(PeriphrastischeDeklination::new(
+                    self.clone(),
+                    steigerung,
+                )),
+            });
+        }
+
         Some(match steigerung {
             Steigerung::Positiv => Deklination::Ao(self.clone()),
             Steigerung::Komperativ => {
                 Deklination::Komperativ(KomperativDeklination::new(self.stamm))
             }
-            Steigerung::Superlativ => {
-                Deklination::Superlativ(SuperlativDeklination::new(self.stamm))
-            }
+            Steigerung::Superlativ => Deklination::Superlativ(SuperlativDeklination::new(
+                self.stamm,
+                self.nominativ_singular_maskulinum,
+            )),
         })
     }
 }
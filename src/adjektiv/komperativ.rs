@@ -1,62 +1,175 @@
 use crate::{
-    adjektiv::kons_dekl::get_endung,
-    grammatik::{Genus, Kasus, Numerus},
+    adjektiv::{
+        kons_dekl::get_endung_konsonantisch, parse_muster, unregelmaessig::Komperativ,
+        Ablehnungsgrund, Deklinationsklasse, Klassifikation, WörterbuchEintrag,
+    },
+    grammatik::{test_form, Genus, Kasus, Numerus},
 };
 
+#[derive(Clone)]
 pub struct KomperativDeklination<'a> {
     positiv_stamm: &'a str,
+    unregelmäßig: Option<&'static Komperativ>,
 }
 
 impl<'a> KomperativDeklination<'a> {
     pub fn new(positiv_stamm: &'a str) -> Self {
-        Self { positiv_stamm }
+        Self {
+            positiv_stamm,
+            unregelmäßig: None,
+        }
+    }
+
+    pub fn new_unregelmäßig(positiv_stamm: &'a str, komperativ: &'static Komperativ) -> Self {
+        Self {
+            positiv_stamm,
+            unregelmäßig: Some(komperativ),
+        }
+    }
+
+    // prior, prius
+    fn parse_ior_ius_long(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, Ablehnungsgrund> {
+        let &WörterbuchEintrag {
+            erste_form,
+            zweite_form: Some(zweite_form),
+            dritte_form: None,
+            ..
+        } = eintrag
+        else {
+            return Err(Ablehnungsgrund::FormenAnzahlPasstNicht);
+        };
+
+        let stamm = erste_form
+            .strip_suffix("ior")
+            .ok_or(Ablehnungsgrund::ErsteFormPasstNicht)?;
+
+        if !test_form(zweite_form, stamm, "ius") {
+            return Err(Ablehnungsgrund::ZweiteFormPasstNicht);
+        }
+
+        Ok(Self::new(stamm))
+    }
+
+    // prior, ius
+    fn parse_ior_ius_short(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, Ablehnungsgrund> {
+        let &WörterbuchEintrag {
+            erste_form,
+            zweite_form: Some(zweite_form),
+            dritte_form: None,
+            ..
+        } = eintrag
+        else {
+            return Err(Ablehnungsgrund::FormenAnzahlPasstNicht);
+        };
+
+        let stamm = erste_form
+            .strip_suffix("ior")
+            .ok_or(Ablehnungsgrund::ErsteFormPasstNicht)?;
+
+        if zweite_form != "ius" {
+            return Err(Ablehnungsgrund::ZweiteFormPasstNicht);
+        }
+
+        Ok(Self::new(stamm))
+    }
+
+    // Einträge ohne Positiv, die mit dem Komperativ beginnen: prior, citerior, ulterior
+    pub fn parse(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, Ablehnungsgrund> {
+        parse_muster(
+            eintrag,
+            &[Self::parse_ior_ius_long, Self::parse_ior_ius_short],
+        )
+    }
+
+    // Stamm, Stammerweiterung und Endung des Neutrums im Nominativ Singular
+    fn get_bestandteile(&self) -> (&'a str, &'static str, &'static str) {
+        match self.unregelmäßig {
+            Some(komperativ) => (
+                komperativ.stamm,
+                komperativ.erweiterung,
+                komperativ.endung_neutrum,
+            ),
+            None => (self.positiv_stamm, "ior", "ius"),
+        }
+    }
+
+    fn ist_plus(&self) -> bool {
+        self.unregelmäßig
+            .is_some_and(|komperativ| komperativ.nur_neutrum_singular)
+    }
+
+    pub fn klassifikation(&self) -> Klassifikation<'a> {
+        Klassifikation {
+            klasse: Deklinationsklasse::Komperativ,
+            stamm: self.positiv_stamm,
+        }
     }
 
     pub fn adverb(&self) -> String {
-        const ENDUNG: &'static str = "ius";
-        let mut adverb = String::with_capacity(self.positiv_stamm.len() + ENDUNG.len());
-        adverb.push_str(self.positiv_stamm);
-        adverb.push_str(ENDUNG);
+        if let Some(komperativ) = self.unregelmäßig {
+            return String::from(komperativ.adverb);
+        }
+
+        let (stamm, _, endung_neutrum) = self.get_bestandteile();
+        let mut adverb = String::with_capacity(stamm.len() + endung_neutrum.len());
+        adverb.push_str(stamm);
+        adverb.push_str(endung_neutrum);
         adverb
     }
 
-    pub fn deklinieren(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> String {
-        const STAMM_ERWEITERUNG: &'static str = "ior";
-        const ENDUNG_NEUTRUM: &'static str = "ius";
+    pub fn deklinieren(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> Option<String> {
+        let (stamm, erweiterung, endung_neutrum) = self.get_bestandteile();
 
-        match (genus, numerus, kasus) {
+        // plus wird im Singular nur substantivisch im Neutrum gebraucht
+        if let (Genus::Maskulinum | Genus::Femininum, Numerus::Singular) = (genus, numerus) {
+            if self.ist_plus() {
+                return None;
+            }
+        }
+
+        let endung = match (genus, numerus, kasus) {
             (
                 Genus::Maskulinum | Genus::Femininum,
                 Numerus::Singular,
                 Kasus::Nominativ | Kasus::Vokativ,
             ) => {
-                let mut form =
-                    String::with_capacity(self.positiv_stamm.len() + STAMM_ERWEITERUNG.len());
-                form.push_str(self.positiv_stamm);
-                form.push_str(STAMM_ERWEITERUNG);
-                return form;
+                let mut form = String::with_capacity(stamm.len() + erweiterung.len());
+                form.push_str(stamm);
+                form.push_str(erweiterung);
+                return Some(form);
             }
             (
                 Genus::Neutrum,
                 Numerus::Singular,
                 Kasus::Nominativ | Kasus::Akkusativ | Kasus::Vokativ,
             ) => {
-                let mut form =
-                    String::with_capacity(self.positiv_stamm.len() + ENDUNG_NEUTRUM.len());
-                form.push_str(self.positiv_stamm);
-                form.push_str(ENDUNG_NEUTRUM);
-                return form;
+                let mut form = String::with_capacity(stamm.len() + endung_neutrum.len());
+                form.push_str(stamm);
+                form.push_str(endung_neutrum);
+                return Some(form);
             }
-            _ => (),
-        }
+            // plus hat im Genitiv Plural die Endung des i-Stamms: plurium
+            (_, Numerus::Plural, Kasus::Genitiv) if self.ist_plus() => "ium",
+            _ => get_endung_konsonantisch(genus, numerus, kasus)?,
+        };
 
-        let endung = get_endung(genus, numerus, kasus);
-        let mut form = String::with_capacity(
-            self.positiv_stamm.len() + STAMM_ERWEITERUNG.len() + endung.len(),
-        );
-        form.push_str(self.positiv_stamm);
-        form.push_str(STAMM_ERWEITERUNG);
+        let mut form = String::with_capacity(stamm.len() + erweiterung.len() + endung.len());
+        form.push_str(stamm);
+        form.push_str(erweiterung);
         form.push_str(endung);
-        form
+        Some(form)
+    }
+
+    pub fn varianten(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> Vec<String> {
+        let Some(form) = self.deklinieren(genus, numerus, kasus) else {
+            return Vec::new();
+        };
+
+        let mut varianten = vec![form];
+        if let (Numerus::Singular, Kasus::Ablativ) = (numerus, kasus) {
+            let (stamm, erweiterung, _) = self.get_bestandteile();
+            varianten.push([stamm, erweiterung, "i"].concat());
+        }
+        varianten
     }
 }
@@ -1,19 +1,21 @@
 use crate::grammatik::{test_form, Genus, Kasus, Numerus, Steigerung};
 
 use super::{
-    komperativ::KomperativDeklination, superlativ::SuperlativDeklination, Deklination,
-    WörterbuchEintrag,
+    komperativ::KomperativDeklination, parse_muster, superlativ::SuperlativDeklination,
+    Ablehnungsgrund, Deklination, Deklinationsklasse, Klassifikation, WörterbuchEintrag,
 };
 
-pub fn get_endung(genus: Genus, numerus: Numerus, kasus: Kasus) -> &'static str {
-    match genus {
+// Nominativ und Vokativ Singular sowie der Akkusativ Singular Neutrum hängen vom Eintrag ab
+pub fn get_endung(genus: Genus, numerus: Numerus, kasus: Kasus) -> Option<&'static str> {
+    Some(match genus {
         Genus::Maskulinum | Genus::Femininum => match numerus {
             Numerus::Singular => match kasus {
-                Kasus::Nominativ | Kasus::Vokativ => panic!(),
+                Kasus::Nominativ | Kasus::Vokativ => return None,
                 Kasus::Genitiv => "is",
                 Kasus::Dativ => "i",
                 Kasus::Akkusativ => "em",
                 Kasus::Ablativ => "i",
+                Kasus::Lokativ => "i",
             },
             Numerus::Plural => match kasus {
                 Kasus::Nominativ | Kasus::Vokativ => "es",
@@ -21,14 +23,16 @@ pub fn get_endung(genus: Genus, numerus: Numerus, kasus: Kasus) -> &'static str
                 Kasus::Dativ => "ibus",
                 Kasus::Akkusativ => "es",
                 Kasus::Ablativ => "ibus",
+                Kasus::Lokativ => "ibus",
             },
         },
         Genus::Neutrum => match numerus {
             Numerus::Singular => match kasus {
-                Kasus::Nominativ | Kasus::Vokativ | Kasus::Akkusativ => panic!(),
+                Kasus::Nominativ | Kasus::Vokativ | Kasus::Akkusativ => return None,
                 Kasus::Genitiv => "is",
                 Kasus::Dativ => "i",
                 Kasus::Ablativ => "i",
+                Kasus::Lokativ => "i",
             },
             Numerus::Plural => match kasus {
                 Kasus::Nominativ | Kasus::Vokativ => "ia",
@@ -36,136 +40,184 @@ pub fn get_endung(genus: Genus, numerus: Numerus, kasus: Kasus) -> &'static str
                 Kasus::Dativ => "ibus",
                 Kasus::Akkusativ => "ia",
                 Kasus::Ablativ => "ibus",
+                Kasus::Lokativ => "ibus",
             },
         },
-    }
+    })
+}
+
+// Konsonantische Endungen ohne i-Stamm, zB beim Komperativ: meliore, meliorum, meliora
+pub fn get_endung_konsonantisch(
+    genus: Genus,
+    numerus: Numerus,
+    kasus: Kasus,
+) -> Option<&'static str> {
+    Some(match numerus {
+        Numerus::Singular => match kasus {
+            Kasus::Nominativ | Kasus::Vokativ => return None,
+            Kasus::Akkusativ if matches!(genus, Genus::Neutrum) => return None,
+            Kasus::Genitiv => "is",
+            Kasus::Dativ => "i",
+            Kasus::Akkusativ => "em",
+            Kasus::Ablativ => "e",
+            Kasus::Lokativ => "e",
+        },
+        Numerus::Plural => match kasus {
+            Kasus::Nominativ | Kasus::Vokativ | Kasus::Akkusativ
+                if matches!(genus, Genus::Neutrum) =>
+            {
+                "a"
+            }
+            Kasus::Nominativ | Kasus::Vokativ => "es",
+            Kasus::Genitiv => "um",
+            Kasus::Dativ => "ibus",
+            Kasus::Akkusativ => "es",
+            Kasus::Ablativ => "ibus",
+            Kasus::Lokativ => "ibus",
+        },
+    })
 }
 
+// Einendige Adjektive, die nicht als i-Stamm dekliniert werden: vetere, veterum, vetera
+const KONSONANTENSTÄMME: [&str; 8] = [
+    "vetus",
+    "dives",
+    "pauper",
+    "princeps",
+    "particeps",
+    "compos",
+    "superstes",
+    "caelebs",
+];
+
 #[derive(Clone)]
 pub struct KonsonantischeDeklination<'a> {
     nominativ_singular_maskulinum: &'a str,
     nominativ_singular_femininum: &'a str,
     nominativ_singular_neutrum: (&'a str, &'a str), // Kann aus zwei Teilen bestehen, zB bei fortis, e
     stamm: &'a str,
+    i_stamm: bool,
 }
 
 impl<'a> KonsonantischeDeklination<'a> {
     // vehenens, vehenentis
-    fn parse_einendig(eintrag: &WörterbuchEintrag<'a>) -> Option<Self> {
+    fn parse_einendig(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, Ablehnungsgrund> {
         let &WörterbuchEintrag {
             erste_form,
             zweite_form: Some(zweite_form),
             dritte_form: None,
+            ..
         } = eintrag else {
-            return None;
+            return Err(Ablehnungsgrund::FormenAnzahlPasstNicht);
         };
 
-        let stamm = if zweite_form.ends_with("is") {
-            &zweite_form[..zweite_form.len() - 2]
-        } else {
-            return None;
-        };
+        let stamm = zweite_form
+            .strip_suffix("is")
+            .ok_or(Ablehnungsgrund::ZweiteFormPasstNicht)?;
 
-        Some(Self {
+        Ok(Self {
             nominativ_singular_maskulinum: erste_form,
             nominativ_singular_femininum: erste_form,
             nominativ_singular_neutrum: (erste_form, ""),
             stamm,
+            i_stamm: !KONSONANTENSTÄMME.contains(&erste_form),
         })
     }
 
     // fortis, e
-    fn parse_zweiendig_short(eintrag: &WörterbuchEintrag<'a>) -> Option<Self> {
+    fn parse_zweiendig_short(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, Ablehnungsgrund> {
         let &WörterbuchEintrag {
             erste_form,
-            zweite_form: Some("e"),
+            zweite_form: Some(zweite_form),
             dritte_form: None,
+            ..
         } = eintrag else {
-            return None;
+            return Err(Ablehnungsgrund::FormenAnzahlPasstNicht);
         };
 
-        let stamm = if erste_form.ends_with("is") {
-            &erste_form[..erste_form.len() - 2]
-        } else {
-            return None;
-        };
+        let stamm = erste_form
+            .strip_suffix("is")
+            .ok_or(Ablehnungsgrund::ErsteFormPasstNicht)?;
 
-        Some(Self {
+        if zweite_form != "e" {
+            return Err(Ablehnungsgrund::ZweiteFormPasstNicht);
+        }
+
+        Ok(Self {
             nominativ_singular_maskulinum: erste_form,
             nominativ_singular_femininum: erste_form,
             nominativ_singular_neutrum: (stamm, "e"),
             stamm,
+            i_stamm: true,
         })
     }
 
     // fortis, forte
-    fn parse_zweiendig_long(eintrag: &WörterbuchEintrag<'a>) -> Option<Self> {
+    fn parse_zweiendig_long(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, Ablehnungsgrund> {
         let &WörterbuchEintrag {
             erste_form,
             zweite_form: Some(zweite_form),
             dritte_form: None,
+            ..
         } = eintrag else {
-            return None;
+            return Err(Ablehnungsgrund::FormenAnzahlPasstNicht);
         };
 
-        let stamm = if erste_form.ends_with("is") {
-            &zweite_form[..erste_form.len() - 2]
-        } else {
-            return None;
-        };
+        let stamm = erste_form
+            .strip_suffix("is")
+            .ok_or(Ablehnungsgrund::ErsteFormPasstNicht)?;
 
         if !test_form(zweite_form, stamm, "e") {
-            return None;
+            return Err(Ablehnungsgrund::ZweiteFormPasstNicht);
         }
 
-        Some(Self {
+        Ok(Self {
             nominativ_singular_maskulinum: erste_form,
             nominativ_singular_femininum: erste_form,
             nominativ_singular_neutrum: (zweite_form, ""),
             stamm,
+            i_stamm: true,
         })
     }
 
     // acer, acris, acre
-    fn parse_dreiendig(eintrag: &WörterbuchEintrag<'a>) -> Option<Self> {
+    fn parse_dreiendig(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, Ablehnungsgrund> {
         let &WörterbuchEintrag {
             erste_form,
             zweite_form: Some(zweite_form),
             dritte_form: Some(dritte_form),
+            ..
         } = eintrag else {
-            return None;
+            return Err(Ablehnungsgrund::FormenAnzahlPasstNicht);
         };
 
-        let stamm = if zweite_form.ends_with("is") {
-            &zweite_form[..zweite_form.len() - 2]
-        } else {
-            return None;
-        };
+        let stamm = zweite_form
+            .strip_suffix("is")
+            .ok_or(Ablehnungsgrund::ZweiteFormPasstNicht)?;
 
         if !test_form(dritte_form, stamm, "e") {
-            return None;
+            return Err(Ablehnungsgrund::DritteFormPasstNicht);
         }
 
-        Some(Self {
+        Ok(Self {
             nominativ_singular_maskulinum: erste_form,
             nominativ_singular_femininum: zweite_form,
             nominativ_singular_neutrum: (dritte_form, ""),
             stamm,
+            i_stamm: true,
         })
     }
 
-    pub fn parse(eintrag: &WörterbuchEintrag<'a>) -> Option<Self> {
-        if let result @ Some(_) = Self::parse_einendig(eintrag) {
-            result
-        } else if let result @ Some(_) = Self::parse_zweiendig_short(eintrag) {
-            result
-        } else if let result @ Some(_) = Self::parse_zweiendig_long(eintrag) {
-            result
-        } else if let result @ Some(_) = Self::parse_dreiendig(eintrag) {
-            result
-        } else {
-            None
-        }
+    pub fn parse(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, Ablehnungsgrund> {
+        parse_muster(
+            eintrag,
+            &[
+                Self::parse_einendig,
+                Self::parse_zweiendig_short,
+                Self::parse_zweiendig_long,
+                Self::parse_dreiendig,
+            ],
+        )
     }
 
     fn get_nominativ_singular_neutrum(&self) -> String {
@@ -177,6 +229,23 @@ impl<'a> KonsonantischeDeklination<'a> {
         form
     }
 
+    pub fn klassifikation(&self) -> Klassifikation<'a> {
+        let klasse = if self.nominativ_singular_maskulinum != self.nominativ_singular_femininum {
+            Deklinationsklasse::KonsonantischeDreiendig
+        } else if !self.i_stamm {
+            Deklinationsklasse::Konsonantenstamm
+        } else if self.nominativ_singular_neutrum == (self.nominativ_singular_maskulinum, "") {
+            Deklinationsklasse::KonsonantischeEinendig
+        } else {
+            Deklinationsklasse::KonsonantischeZweiendig
+        };
+
+        Klassifikation {
+            klasse,
+            stamm: self.stamm,
+        }
+    }
+
     pub fn adverb(&self) -> String {
         let adverb_endung = if self.stamm.ends_with("nt") {
             "er"
@@ -189,24 +258,51 @@ impl<'a> KonsonantischeDeklination<'a> {
         adverb
     }
 
-    pub fn deklinieren(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> String {
+    pub fn deklinieren(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> Option<String> {
         if let (Kasus::Nominativ | Kasus::Vokativ, Numerus::Singular) = (kasus, numerus) {
-            return String::from(match genus {
-                Genus::Maskulinum => self.nominativ_singular_maskulinum,
-                Genus::Femininum => self.nominativ_singular_femininum,
-                Genus::Neutrum => return self.get_nominativ_singular_neutrum(),
+            return Some(match genus {
+                Genus::Maskulinum => String::from(self.nominativ_singular_maskulinum),
+                Genus::Femininum => String::from(self.nominativ_singular_femininum),
+                Genus::Neutrum => self.get_nominativ_singular_neutrum(),
             });
         }
 
         if let (Kasus::Akkusativ, Numerus::Singular, Genus::Neutrum) = (kasus, numerus, genus) {
-            return self.get_nominativ_singular_neutrum();
+            return Some(self.get_nominativ_singular_neutrum());
         }
 
-        let endung = get_endung(genus, numerus, kasus);
+        let endung = if self.i_stamm {
+            get_endung(genus, numerus, kasus)?
+        } else {
+            get_endung_konsonantisch(genus, numerus, kasus)?
+        };
         let mut form = String::with_capacity(self.stamm.len() + endung.len());
         form.push_str(self.stamm);
         form.push_str(endung);
-        form
+        Some(form)
+    }
+
+    pub fn varianten(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> Vec<String> {
+        let einendig = matches!(
+            self.klassifikation().get_klasse(),
+            Deklinationsklasse::KonsonantischeEinendig
+        );
+        let variante = match (genus, numerus, kasus) {
+            _ if !self.i_stamm => None,
+            (Genus::Maskulinum | Genus::Femininum, Numerus::Plural, Kasus::Akkusativ) => Some("is"),
+            (_, Numerus::Singular, Kasus::Ablativ) if einendig => Some("e"),
+            _ => None,
+        };
+
+        let Some(form) = self.deklinieren(genus, numerus, kasus) else {
+            return Vec::new();
+        };
+
+        let mut varianten = vec![form];
+        if let Some(endung) = variante {
+            varianten.push([self.stamm, endung].concat());
+        }
+        varianten
     }
 
     pub(super) fn steigern(&self, steigerung: Steigerung) -> Option<Deklination<'a>> {
@@ -215,9 +311,10 @@ impl<'a> KonsonantischeDeklination<'a> {
             Steigerung::Komperativ => {
                 Deklination::Komperativ(KomperativDeklination::new(self.stamm))
             }
-            Steigerung::Superlativ => {
-                Deklination::Superlativ(SuperlativDeklination::new(self.stamm))
-            }
+            Steigerung::Superlativ => Deklination::Superlativ(SuperlativDeklination::new(
+                self.stamm,
+                Some(self.nominativ_singular_maskulinum),
+            )),
         })
     }
 }
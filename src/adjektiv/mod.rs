@@ -1,62 +1,271 @@
-use crate::grammatik::{Genus, Kasus, Numerus, Steigerung};
+use std::fmt;
+
+use crate::grammatik::{self, Genus, Kasus, Numerus, Steigerung};
 
 use self::{
-    ao_dekl::AODeklination, komperativ::KomperativDeklination,
-    kons_dekl::KonsonantischeDeklination, superlativ::SuperlativDeklination,
+    adverb::{weitere_varianten, Adverbbildung},
+    ao_dekl::AODeklination,
+    komperativ::KomperativDeklination,
+    kons_dekl::KonsonantischeDeklination,
+    periphrastisch::PeriphrastischeDeklination,
+    pronominal::PronominaleDeklination,
+    superlativ::SuperlativDeklination,
+    unregelmaessig::Superlative,
 };
 
-mod ao_dekl;
+mod adverb;
+pub(crate) mod ao_dekl;
 mod komperativ;
 mod kons_dekl;
+mod periphrastisch;
+mod pronominal;
 mod superlativ;
+mod unregelmaessig;
 
 #[cfg(test)]
 mod tests;
 
 enum Deklination<'a> {
     Ao(AODeklination<'a>),
+    Pronominal(PronominaleDeklination<'a>),
     Konsonantische(KonsonantischeDeklination<'a>),
     Komperativ(KomperativDeklination<'a>),
     Superlativ(SuperlativDeklination<'a>),
+    Periphrastisch(PeriphrastischeDeklination<'a>),
 }
 
 impl<'a> Deklination<'a> {
-    fn parse(eintrag: &WörterbuchEintrag<'a>) -> Option<Self> {
-        if let Some(deklination) = AODeklination::parse(eintrag) {
-            Some(Self::Ao(deklination))
-        } else if let Some(deklination) = KonsonantischeDeklination::parse(eintrag) {
-            Some(Self::Konsonantische(deklination))
-        } else {
-            None
-        }
+    fn parse(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, ParseFehler> {
+        let ao_grund = match AODeklination::parse(eintrag) {
+            Ok(deklination)
+                if eintrag.pronominal || PronominaleDeklination::ist_pronominal(&deklination) =>
+            {
+                return Ok(Self::Pronominal(PronominaleDeklination::new(deklination)))
+            }
+            Ok(deklination) => return Ok(Self::Ao(deklination)),
+            Err(grund) => grund,
+        };
+        let pronominale_grund = match PronominaleDeklination::parse_neutrum_ud(eintrag) {
+            Ok(deklination) => return Ok(Self::Pronominal(deklination)),
+            Err(grund) => grund,
+        };
+        let konsonantische_grund = match KonsonantischeDeklination::parse(eintrag) {
+            Ok(deklination) => return Ok(Self::Konsonantische(deklination)),
+            Err(grund) => grund,
+        };
+        let komperativ_grund = match KomperativDeklination::parse(eintrag) {
+            Ok(deklination) => return Ok(Self::Komperativ(deklination)),
+            Err(grund) => grund,
+        };
+
+        Err(ParseFehler::KeineDeklination(vec![
+            Ablehnung::new("a-/o-Deklination", ao_grund),
+            Ablehnung::new("pronominale Deklination", pronominale_grund),
+            Ablehnung::new("konsonantische Deklination", konsonantische_grund),
+            Ablehnung::new("Komperativ", komperativ_grund),
+        ]))
     }
 
-    fn deklinieren(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> String {
+    fn deklinieren(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> Option<String> {
         match *self {
             Self::Ao(ref deklination) => deklination.deklinieren(genus, numerus, kasus),
+            Self::Pronominal(ref deklination) => deklination.deklinieren(genus, numerus, kasus),
             Self::Konsonantische(ref deklination) => deklination.deklinieren(genus, numerus, kasus),
             Self::Komperativ(ref deklination) => deklination.deklinieren(genus, numerus, kasus),
             Self::Superlativ(ref deklination) => deklination.deklinieren(genus, numerus, kasus),
+            Self::Periphrastisch(ref deklination) => deklination.deklinieren(genus, numerus, kasus),
         }
     }
 
-    fn adverb(&self) -> String {
+    fn varianten(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> Vec<String> {
         match *self {
+            Self::Pronominal(ref deklination) => deklination.varianten(genus, numerus, kasus),
+            Self::Konsonantische(ref deklination) => deklination.varianten(genus, numerus, kasus),
+            Self::Komperativ(ref deklination) => deklination.varianten(genus, numerus, kasus),
+            _ => self
+                .deklinieren(genus, numerus, kasus)
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    fn adverb(&self) -> Option<String> {
+        if let Self::Ao(_) | Self::Pronominal(_) | Self::Konsonantische(_) = *self {
+            let nominativ =
+                self.deklinieren(Genus::Maskulinum, Numerus::Singular, Kasus::Nominativ)?;
+            if let Some(bildung) = Adverbbildung::finden(&nominativ) {
+                return match bildung {
+                    Adverbbildung::Ablativ => {
+                        self.deklinieren(Genus::Neutrum, Numerus::Singular, Kasus::Ablativ)
+                    }
+                    Adverbbildung::Akkusativ => {
+                        self.deklinieren(Genus::Neutrum, Numerus::Singular, Kasus::Akkusativ)
+                    }
+                    Adverbbildung::Unregelmäßig(adverb) => Some(String::from(adverb)),
+                    Adverbbildung::Keines => None,
+                };
+            }
+        }
+
+        Some(match *self {
             Self::Ao(ref deklination) => deklination.adverb(),
+            Self::Pronominal(ref deklination) => deklination.adverb(),
             Self::Konsonantische(ref deklination) => deklination.adverb(),
             Self::Komperativ(ref deklination) => deklination.adverb(),
             Self::Superlativ(ref deklination) => deklination.adverb(),
+            Self::Periphrastisch(ref deklination) => deklination.adverb(),
+        })
+    }
+
+    fn adverb_varianten(&self) -> Vec<String> {
+        let Some(adverb) = self.adverb() else {
+            return Vec::new();
+        };
+        let weitere = weitere_varianten(&adverb);
+        let mut varianten = vec![adverb];
+        varianten.extend(weitere.iter().map(|&variante| String::from(variante)));
+        varianten
+    }
+
+    fn klassifikation(&self) -> Klassifikation<'a> {
+        match *self {
+            Self::Ao(ref deklination) => deklination.klassifikation(),
+            Self::Pronominal(ref deklination) => deklination.klassifikation(),
+            Self::Konsonantische(ref deklination) => deklination.klassifikation(),
+            Self::Komperativ(ref deklination) => deklination.klassifikation(),
+            Self::Superlativ(ref deklination) => deklination.klassifikation(),
+            Self::Periphrastisch(ref deklination) => deklination.klassifikation(),
         }
     }
 
+    fn steigerungen(&self, steigerung: Steigerung) -> Vec<Deklination<'a>> {
+        let klassifikation = self.klassifikation();
+        if let (
+            Steigerung::Superlativ,
+            Deklinationsklasse::AODeklination | Deklinationsklasse::Komperativ,
+        ) = (steigerung, klassifikation.get_klasse())
+        {
+            let stamm = klassifikation.get_stamm();
+            if let Some(superlative) = Superlative::finden(stamm) {
+                return superlative
+                    .superlative
+                    .iter()
+                    .map(|superlativ| {
+                        Deklination::Superlativ(SuperlativDeklination::new_unregelmäßig(
+                            stamm, superlativ,
+                        ))
+                    })
+                    .collect();
+            }
+        }
+
+        self.steigern(steigerung).into_iter().collect()
+    }
+
     fn steigern(&self, steigerung: Steigerung) -> Option<Deklination<'a>> {
         match *self {
             Self::Ao(ref deklination) => deklination.steigern(steigerung),
+            Self::Pronominal(ref deklination) => deklination.steigern(steigerung),
             Self::Konsonantische(ref deklination) => deklination.steigern(steigerung),
-            Self::Komperativ(_) => None,
-            Self::Superlativ(_) => None,
+            // Einträge, die mit dem Komperativ beginnen: prior, prius
+            Self::Komperativ(ref deklination) if steigerung == Steigerung::Komperativ => {
+                Some(Self::Komperativ(deklination.clone()))
+            }
+            Self::Superlativ(ref deklination) if steigerung == Steigerung::Superlativ => {
+                Some(Self::Superlativ(deklination.clone()))
+            }
+            Self::Periphrastisch(ref deklination) if steigerung == deklination.get_steigerung() => {
+                Some(Self::Periphrastisch(deklination.clone()))
+            }
+            Self::Komperativ(_) | Self::Superlativ(_) | Self::Periphrastisch(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub enum Deklinationsklasse {
+    AODeklination,
+    Pronominal,
+    KonsonantischeEinendig,
+    Konsonantenstamm, // Einendig ohne i-Stamm: vetus, veteris
+    KonsonantischeZweiendig,
+    KonsonantischeDreiendig,
+    Komperativ,
+    Superlativ,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Klassifikation<'a> {
+    klasse: Deklinationsklasse,
+    stamm: &'a str, // Bei Komperativ und Superlativ der Stamm des Positivs
+}
+
+impl<'a> Klassifikation<'a> {
+    pub fn get_klasse(&self) -> Deklinationsklasse {
+        self.klasse
+    }
+
+    pub fn get_stamm(&self) -> &'a str {
+        self.stamm
+    }
+}
+
+// Die Reihenfolge gibt an, wie weit ein Muster zum Eintrag gepasst hat
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Ablehnungsgrund {
+    FormenAnzahlPasstNicht,
+    ErsteFormPasstNicht,
+    ZweiteFormPasstNicht,
+    DritteFormPasstNicht,
+}
+
+impl fmt::Display for Ablehnungsgrund {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::FormenAnzahlPasstNicht => write!(f, "Anzahl der Formen passt nicht"),
+            Self::ErsteFormPasstNicht => write!(f, "erste Form passt nicht"),
+            Self::ZweiteFormPasstNicht => write!(f, "zweite Form passt nicht"),
+            Self::DritteFormPasstNicht => write!(f, "dritte Form passt nicht"),
+        }
+    }
+}
+
+pub type Ablehnung = grammatik::Ablehnung<Ablehnungsgrund>;
+
+#[derive(Debug)]
+pub enum ParseFehler {
+    KeineDeklination(Vec<Ablehnung>),
+}
+
+impl fmt::Display for ParseFehler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::KeineDeklination(ref ablehnungen) => {
+                write!(f, "keine passende Deklination: ")?;
+                Ablehnung::fmt_liste(ablehnungen, f)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseFehler {}
+
+type Muster<'a, T> = fn(&WörterbuchEintrag<'a>) -> Result<T, Ablehnungsgrund>;
+
+// Probiert alle Muster einer Deklination aus und gibt bei Misserfolg den Grund des Musters
+// zurück, das am weitesten gepasst hat
+fn parse_muster<'a, T>(
+    eintrag: &WörterbuchEintrag<'a>,
+    muster: &[Muster<'a, T>],
+) -> Result<T, Ablehnungsgrund> {
+    let mut fehler = Ablehnungsgrund::FormenAnzahlPasstNicht;
+    for parse in muster {
+        match parse(eintrag) {
+            Ok(deklination) => return Ok(deklination),
+            Err(grund) => fehler = fehler.max(grund),
         }
     }
+    Err(fehler)
 }
 
 #[derive(Debug)]
@@ -64,6 +273,7 @@ pub struct WörterbuchEintrag<'a> {
     erste_form: &'a str,
     zweite_form: Option<&'a str>,
     dritte_form: Option<&'a str>,
+    pronominal: bool, // Genitiv -ius und Dativ -i wie bei unus, solus, totus
 }
 
 impl<'a> WörterbuchEintrag<'a> {
@@ -72,6 +282,7 @@ impl<'a> WörterbuchEintrag<'a> {
             erste_form,
             zweite_form: None,
             dritte_form: None,
+            pronominal: false,
         }
     }
 
@@ -80,6 +291,7 @@ impl<'a> WörterbuchEintrag<'a> {
             erste_form,
             zweite_form: Some(zweite_form),
             dritte_form: None,
+            pronominal: false,
         }
     }
 
@@ -88,6 +300,16 @@ impl<'a> WörterbuchEintrag<'a> {
             erste_form,
             zweite_form: Some(zweite_form),
             dritte_form: Some(dritte_form),
+            pronominal: false,
+        }
+    }
+
+    // Markiert ein Adjektiv, das nicht in der Liste der pronominalen Adjektive steht, als
+    // pronominal
+    pub fn pronominal(self) -> Self {
+        Self {
+            pronominal: true,
+            ..self
         }
     }
 }
@@ -97,28 +319,45 @@ pub struct Adjektiv<'a> {
 }
 
 impl<'a> Adjektiv<'a> {
-    pub fn parse(eintrag: &WörterbuchEintrag<'a>) -> Option<Self> {
-        let Some(deklination) = Deklination::parse(eintrag) else {
-            return None;
-        };
+    pub fn parse(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, ParseFehler> {
+        let deklination = Deklination::parse(eintrag)?;
 
-        Some(Self { deklination })
+        Ok(Self { deklination })
     }
 
-    pub fn deklinieren(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> String {
+    pub fn deklinieren(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> Option<String> {
         self.deklination.deklinieren(genus, numerus, kasus)
     }
 
-    pub fn adverb(&self) -> String {
+    // Alle zulässigen Formen, die bevorzugte Form zuerst
+    pub fn varianten(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> Vec<String> {
+        self.deklination.varianten(genus, numerus, kasus)
+    }
+
+    // None, wenn das Adjektiv kein Adverb bildet
+    pub fn adverb(&self) -> Option<String> {
         self.deklination.adverb()
     }
 
+    // Das bevorzugte Adverb zuerst: primo, primum
+    pub fn adverb_varianten(&self) -> Vec<String> {
+        self.deklination.adverb_varianten()
+    }
+
+    pub fn get_klassifikation(&self) -> Klassifikation<'a> {
+        self.deklination.klassifikation()
+    }
+
     pub fn steigern(&self, steigerung: Steigerung) -> Option<Self> {
-        Some(Self {
-            deklination: match self.deklination.steigern(steigerung) {
-                Some(deklination) => deklination,
-                None => return None,
-            },
-        })
+        self.steigerungen(steigerung).into_iter().next()
+    }
+
+    // Alle Formen einer Steigerungsstufe, die bevorzugte Form zuerst: summus, supremus
+    pub fn steigerungen(&self, steigerung: Steigerung) -> Vec<Self> {
+        self.deklination
+            .steigerungen(steigerung)
+            .into_iter()
+            .map(|deklination| Self { deklination })
+            .collect()
     }
 }
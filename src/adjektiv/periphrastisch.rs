@@ -0,0 +1,55 @@
+use crate::grammatik::{Genus, Kasus, Numerus, Steigerung};
+
+use super::{ao_dekl::AODeklination, Deklinationsklasse, Klassifikation};
+
+// Steigerung mit magis und maxime bei Adjektiven mit vokalischem Stamm: idoneus, magis idoneus,
+// maxime idoneus
+#[derive(Clone)]
+pub struct PeriphrastischeDeklination<'a> {
+    positiv: AODeklination<'a>,
+    steigerung: Steigerung,
+}
+
+impl<'a> PeriphrastischeDeklination<'a> {
+    pub fn new(positiv: AODeklination<'a>, steigerung: Steigerung) -> Self {
+        Self {
+            positiv,
+            steigerung,
+        }
+    }
+
+    pub fn get_steigerung(&self) -> Steigerung {
+        self.steigerung
+    }
+
+    fn get_partikel(&self) -> &'static str {
+        match self.steigerung {
+            Steigerung::Positiv => "",
+            Steigerung::Komperativ => "magis ",
+            Steigerung::Superlativ => "maxime ",
+        }
+    }
+
+    pub fn klassifikation(&self) -> Klassifikation<'a> {
+        Klassifikation {
+            klasse: match self.steigerung {
+                Steigerung::Positiv => Deklinationsklasse::AODeklination,
+                Steigerung::Komperativ => Deklinationsklasse::Komperativ,
+                Steigerung::Superlativ => Deklinationsklasse::Superlativ,
+            },
+            ..self.positiv.klassifikation()
+        }
+    }
+
+    pub fn adverb(&self) -> String {
+        let mut adverb = String::from(self.get_partikel());
+        adverb.push_str(&self.positiv.adverb());
+        adverb
+    }
+
+    pub fn deklinieren(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> Option<String> {
+        let mut form = String::from(self.get_partikel());
+        form.push_str(&self.positiv.deklinieren(genus, numerus, kasus)?);
+        Some(form)
+    }
+}
@@ -0,0 +1,115 @@
+use crate::grammatik::{test_form, Genus, Kasus, Numerus, Steigerung};
+
+use super::{
+    ao_dekl::AODeklination, Ablehnungsgrund, Deklination, Deklinationsklasse, Klassifikation,
+    WörterbuchEintrag,
+};
+
+// unus, solus, totus, nullus, ullus, alius, alter, uter, neuter: Genitiv -ius, Dativ -i
+const PRONOMINALE_ADJEKTIVE: [&str; 9] = [
+    "unus", "solus", "totus", "nullus", "ullus", "alius", "alter", "uter", "neuter",
+];
+
+#[derive(Clone)]
+pub struct PronominaleDeklination<'a> {
+    positiv: AODeklination<'a>,
+}
+
+impl<'a> PronominaleDeklination<'a> {
+    pub fn new(positiv: AODeklination<'a>) -> Self {
+        Self { positiv }
+    }
+
+    // alius, alia, aliud; die übrigen pronominalen Adjektive werden von der a-/o-Deklination
+    // erkannt, andere Adjektive auf -ud abgelehnt
+    pub fn parse_neutrum_ud(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, Ablehnungsgrund> {
+        let &WörterbuchEintrag {
+            erste_form,
+            zweite_form: Some(zweite_form),
+            dritte_form: Some(dritte_form),
+            ..
+        } = eintrag
+        else {
+            return Err(Ablehnungsgrund::FormenAnzahlPasstNicht);
+        };
+
+        let stamm = erste_form
+            .strip_suffix("us")
+            .filter(|_| PRONOMINALE_ADJEKTIVE.contains(&erste_form))
+            .ok_or(Ablehnungsgrund::ErsteFormPasstNicht)?;
+
+        if !test_form(zweite_form, stamm, "a") {
+            return Err(Ablehnungsgrund::ZweiteFormPasstNicht);
+        }
+        if !test_form(dritte_form, stamm, "ud") {
+            return Err(Ablehnungsgrund::DritteFormPasstNicht);
+        }
+
+        Ok(Self::new(AODeklination::from_stamm(stamm)))
+    }
+
+    pub fn ist_pronominal(positiv: &AODeklination) -> bool {
+        let nominativ = positiv.deklinieren(Genus::Maskulinum, Numerus::Singular, Kasus::Nominativ);
+        matches!(nominativ, Some(nominativ) if PRONOMINALE_ADJEKTIVE.contains(&nominativ.as_str()))
+    }
+
+    // alius, alia, aliud
+    fn ist_alius(&self) -> bool {
+        self.positiv.get_stamm() == "ali"
+    }
+
+    pub fn klassifikation(&self) -> Klassifikation<'a> {
+        Klassifikation {
+            klasse: Deklinationsklasse::Pronominal,
+            ..self.positiv.klassifikation()
+        }
+    }
+
+    pub fn adverb(&self) -> String {
+        self.positiv.adverb()
+    }
+
+    pub fn deklinieren(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> Option<String> {
+        let stamm = self.positiv.get_stamm();
+        let endung = match (genus, numerus, kasus) {
+            (_, Numerus::Singular, Kasus::Genitiv) if self.ist_alius() => {
+                return Some(String::from("alterius"))
+            }
+            (_, Numerus::Singular, Kasus::Genitiv) => "ius",
+            (_, Numerus::Singular, Kasus::Dativ) => "i",
+            (
+                Genus::Neutrum,
+                Numerus::Singular,
+                Kasus::Nominativ | Kasus::Akkusativ | Kasus::Vokativ,
+            ) if self.ist_alius() => "ud",
+            _ => return self.positiv.deklinieren(genus, numerus, kasus),
+        };
+
+        let mut form = String::with_capacity(stamm.len() + endung.len());
+        form.push_str(stamm);
+        form.push_str(endung);
+        Some(form)
+    }
+
+    pub fn varianten(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> Vec<String> {
+        let Some(form) = self.deklinieren(genus, numerus, kasus) else {
+            return Vec::new();
+        };
+
+        let mut varianten = vec![form];
+        if let (Numerus::Singular, Kasus::Genitiv) = (numerus, kasus) {
+            if self.ist_alius() {
+                varianten.push(String::from("alius"));
+            }
+        }
+        varianten
+    }
+
+    // Pronominale Adjektive werden nicht gesteigert
+    pub(super) fn steigern(&self, steigerung: Steigerung) -> Option<Deklination<'a>> {
+        match steigerung {
+            Steigerung::Positiv => Some(Deklination::Pronominal(self.clone())),
+            Steigerung::Komperativ | Steigerung::Superlativ => None,
+        }
+    }
+}
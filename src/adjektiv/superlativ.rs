@@ -1,37 +1,84 @@
 use crate::grammatik::{Genus, Kasus, Numerus};
 
-use super::ao_dekl::{get_endung, ADVERB_ENDUNG};
+use super::{
+    ao_dekl::{get_endung, ADVERB_ENDUNG},
+    unregelmaessig::Superlativ,
+    Deklinationsklasse, Klassifikation,
+};
 
-const STAMM_ERWEITERUNG: &'static str = "issim";
+const STAMM_ERWEITERUNG: &str = "issim";
 
-// TODO: pulcher, pulchra, pulchrum -> pulchrissimus, a, um
+// Adjektive mit Superlativ auf -illimus
+const LIMUS_ADJEKTIVE: [&str; 6] = [
+    "facilis",
+    "difficilis",
+    "similis",
+    "dissimilis",
+    "gracilis",
+    "humilis",
+];
+
+#[derive(Clone)]
 pub struct SuperlativDeklination<'a> {
     positiv_stamm: &'a str,
+    stamm: &'a str,
+    erweiterung: &'static str,
+    adverb: Option<&'static str>,
 }
 
 impl<'a> SuperlativDeklination<'a> {
-    pub fn new(positiv_stamm: &'a str) -> Self {
-        Self { positiv_stamm }
+    // longus -> longissimus, pulcher -> pulcherrimus, facilis -> facillimus
+    pub fn new(positiv_stamm: &'a str, nominativ_singular_maskulinum: Option<&'a str>) -> Self {
+        let (stamm, erweiterung) = match nominativ_singular_maskulinum {
+            Some(nominativ) if nominativ.ends_with("er") => (nominativ, "rim"),
+            Some(nominativ) if LIMUS_ADJEKTIVE.contains(&nominativ) => (positiv_stamm, "lim"),
+            _ => (positiv_stamm, STAMM_ERWEITERUNG),
+        };
+
+        Self {
+            positiv_stamm,
+            stamm,
+            erweiterung,
+            adverb: None,
+        }
+    }
+
+    pub fn new_unregelmäßig(positiv_stamm: &'a str, superlativ: &'static Superlativ) -> Self {
+        Self {
+            positiv_stamm,
+            stamm: superlativ.stamm,
+            erweiterung: "",
+            adverb: Some(superlativ.adverb),
+        }
+    }
+
+    pub fn klassifikation(&self) -> Klassifikation<'a> {
+        Klassifikation {
+            klasse: Deklinationsklasse::Superlativ,
+            stamm: self.positiv_stamm,
+        }
     }
 
     pub fn adverb(&self) -> String {
-        let mut adverb = String::with_capacity(
-            self.positiv_stamm.len() + STAMM_ERWEITERUNG.len() + ADVERB_ENDUNG.len(),
-        );
-        adverb.push_str(self.positiv_stamm);
-        adverb.push_str(STAMM_ERWEITERUNG);
+        if let Some(adverb) = self.adverb {
+            return String::from(adverb);
+        }
+
+        let mut adverb =
+            String::with_capacity(self.stamm.len() + self.erweiterung.len() + ADVERB_ENDUNG.len());
+        adverb.push_str(self.stamm);
+        adverb.push_str(self.erweiterung);
         adverb.push_str(ADVERB_ENDUNG);
         adverb
     }
 
-    pub fn deklinieren(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> String {
-        let endung = get_endung(genus, numerus, kasus);
-        let mut form = String::with_capacity(
-            self.positiv_stamm.len() + STAMM_ERWEITERUNG.len() + endung.len(),
-        );
-        form.push_str(self.positiv_stamm);
-        form.push_str(STAMM_ERWEITERUNG);
+    pub fn deklinieren(&self, genus: Genus, numerus: Numerus, kasus: Kasus) -> Option<String> {
+        let endung = get_endung(genus, numerus, kasus)?;
+        let mut form =
+            String::with_capacity(self.stamm.len() + self.erweiterung.len() + endung.len());
+        form.push_str(self.stamm);
+        form.push_str(self.erweiterung);
         form.push_str(endung);
-        form
+        Some(form)
     }
 }
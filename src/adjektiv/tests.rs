@@ -1,4 +1,4 @@
-use super::{Adjektiv, WörterbuchEintrag};
+use super::{Ablehnungsgrund, Adjektiv, Deklinationsklasse, ParseFehler, WörterbuchEintrag};
 use crate::grammatik::{
     Genus::{Femininum as F, Maskulinum as M, Neutrum as N},
     Kasus::{
@@ -6,23 +6,23 @@ use crate::grammatik::{
         Vokativ as Vok,
     },
     Numerus::{Plural as Pl, Singular as Sg},
-    Steigerung,
+    *,
 };
 
 macro_rules! test_deklination {
     ($eintrag:expr; $($kasus:ident $numerus:ident $genus:ident => $form:literal),* $(,)?) => {
-        let Some(adjektiv) = Adjektiv::parse(&$eintrag) else {
+        let Ok(adjektiv) = Adjektiv::parse(&$eintrag) else {
             panic!("failed to parse adjektiv: {:?}", $eintrag);
         };
         $({
-            let form = adjektiv.deklinieren($genus, $numerus, $kasus);
+            let form = adjektiv.deklinieren($genus, $numerus, $kasus).unwrap();
             assert_eq!(form, $form);
         })*
     };
     (adjektiv => $adjektiv:expr; $($kasus:ident $numerus:ident $genus:ident => $form:literal),* $(,)?) => {
         let adjektiv = $adjektiv;
         $({
-            let form = adjektiv.deklinieren($genus, $numerus, $kasus);
+            let form = adjektiv.deklinieren($genus, $numerus, $kasus).unwrap();
             assert_eq!(form, $form);
         })*
     };
@@ -279,10 +279,10 @@ fn test_komperativ_ao() {
             Gen Sg M => "longioris",
             Dat Sg M => "longiori",
             Akk Sg M => "longiorem",
-            Abl Sg M => "longiori",
+            Abl Sg M => "longiore",
             Vok Sg M => "longior",
             Nom Pl M => "longiores",
-            Gen Pl M => "longiorium",
+            Gen Pl M => "longiorum",
             Dat Pl M => "longioribus",
             Akk Pl M => "longiores",
             Abl Pl M => "longioribus",
@@ -292,10 +292,10 @@ fn test_komperativ_ao() {
             Gen Sg F => "longioris",
             Dat Sg F => "longiori",
             Akk Sg F => "longiorem",
-            Abl Sg F => "longiori",
+            Abl Sg F => "longiore",
             Vok Sg F => "longior",
             Nom Pl F => "longiores",
-            Gen Pl F => "longiorium",
+            Gen Pl F => "longiorum",
             Dat Pl F => "longioribus",
             Akk Pl F => "longiores",
             Abl Pl F => "longioribus",
@@ -305,14 +305,14 @@ fn test_komperativ_ao() {
             Gen Sg N => "longioris",
             Dat Sg N => "longiori",
             Akk Sg N => "longius",
-            Abl Sg N => "longiori",
+            Abl Sg N => "longiore",
             Vok Sg N => "longius",
-            Nom Pl N => "longioria",
-            Gen Pl N => "longiorium",
+            Nom Pl N => "longiora",
+            Gen Pl N => "longiorum",
             Dat Pl N => "longioribus",
-            Akk Pl N => "longioria",
+            Akk Pl N => "longiora",
             Abl Pl N => "longioribus",
-            Vok Pl N => "longioria",
+            Vok Pl N => "longiora",
     };
 }
 
@@ -326,10 +326,10 @@ fn test_komperativ_kons() {
             Gen Sg M => "fortioris",
             Dat Sg M => "fortiori",
             Akk Sg M => "fortiorem",
-            Abl Sg M => "fortiori",
+            Abl Sg M => "fortiore",
             Vok Sg M => "fortior",
             Nom Pl M => "fortiores",
-            Gen Pl M => "fortiorium",
+            Gen Pl M => "fortiorum",
             Dat Pl M => "fortioribus",
             Akk Pl M => "fortiores",
             Abl Pl M => "fortioribus",
@@ -339,10 +339,10 @@ fn test_komperativ_kons() {
             Gen Sg F => "fortioris",
             Dat Sg F => "fortiori",
             Akk Sg F => "fortiorem",
-            Abl Sg F => "fortiori",
+            Abl Sg F => "fortiore",
             Vok Sg F => "fortior",
             Nom Pl F => "fortiores",
-            Gen Pl F => "fortiorium",
+            Gen Pl F => "fortiorum",
             Dat Pl F => "fortioribus",
             Akk Pl F => "fortiores",
             Abl Pl F => "fortioribus",
@@ -352,14 +352,14 @@ fn test_komperativ_kons() {
             Gen Sg N => "fortioris",
             Dat Sg N => "fortiori",
             Akk Sg N => "fortius",
-            Abl Sg N => "fortiori",
+            Abl Sg N => "fortiore",
             Vok Sg N => "fortius",
-            Nom Pl N => "fortioria",
-            Gen Pl N => "fortiorium",
+            Nom Pl N => "fortiora",
+            Gen Pl N => "fortiorum",
             Dat Pl N => "fortioribus",
-            Akk Pl N => "fortioria",
+            Akk Pl N => "fortiora",
             Abl Pl N => "fortioribus",
-            Vok Pl N => "fortioria",
+            Vok Pl N => "fortiora",
     };
 }
 
@@ -416,44 +416,44 @@ fn test_superlativ_kons() {
         adjektiv => Adjektiv::parse(&WörterbuchEintrag::from_three("acer", "acris", "acre")).unwrap()
             .steigern(Steigerung::Superlativ).unwrap();
 
-        Nom Sg M => "acrissimus",
-        Gen Sg M => "acrissimi",
-        Dat Sg M => "acrissimo",
-        Akk Sg M => "acrissimum",
-        Abl Sg M => "acrissimo",
-        Vok Sg M => "acrissime",
-        Nom Pl M => "acrissimi",
-        Gen Pl M => "acrissimorum",
-        Dat Pl M => "acrissimis",
-        Akk Pl M => "acrissimos",
-        Abl Pl M => "acrissimis",
-        Vok Pl M => "acrissimi",
-
-        Nom Sg F => "acrissima",
-        Gen Sg F => "acrissimae",
-        Dat Sg F => "acrissimae",
-        Akk Sg F => "acrissimam",
-        Abl Sg F => "acrissima",
-        Vok Sg F => "acrissima",
-        Nom Pl F => "acrissimae",
-        Gen Pl F => "acrissimarum",
-        Dat Pl F => "acrissimis",
-        Akk Pl F => "acrissimas",
-        Abl Pl F => "acrissimis",
-        Vok Pl F => "acrissimae",
-
-        Nom Sg N => "acrissimum",
-        Gen Sg N => "acrissimi",
-        Dat Sg N => "acrissimo",
-        Akk Sg N => "acrissimum",
-        Abl Sg N => "acrissimo",
-        Vok Sg N => "acrissimum",
-        Nom Pl N => "acrissima",
-        Gen Pl N => "acrissimorum",
-        Dat Pl N => "acrissimis",
-        Akk Pl N => "acrissima",
-        Abl Pl N => "acrissimis",
-        Vok Pl N => "acrissima",
+        Nom Sg M => "acerrimus",
+        Gen Sg M => "acerrimi",
+        Dat Sg M => "acerrimo",
+        Akk Sg M => "acerrimum",
+        Abl Sg M => "acerrimo",
+        Vok Sg M => "acerrime",
+        Nom Pl M => "acerrimi",
+        Gen Pl M => "acerrimorum",
+        Dat Pl M => "acerrimis",
+        Akk Pl M => "acerrimos",
+        Abl Pl M => "acerrimis",
+        Vok Pl M => "acerrimi",
+
+        Nom Sg F => "acerrima",
+        Gen Sg F => "acerrimae",
+        Dat Sg F => "acerrimae",
+        Akk Sg F => "acerrimam",
+        Abl Sg F => "acerrima",
+        Vok Sg F => "acerrima",
+        Nom Pl F => "acerrimae",
+        Gen Pl F => "acerrimarum",
+        Dat Pl F => "acerrimis",
+        Akk Pl F => "acerrimas",
+        Abl Pl F => "acerrimis",
+        Vok Pl F => "acerrimae",
+
+        Nom Sg N => "acerrimum",
+        Gen Sg N => "acerrimi",
+        Dat Sg N => "acerrimo",
+        Akk Sg N => "acerrimum",
+        Abl Sg N => "acerrimo",
+        Vok Sg N => "acerrimum",
+        Nom Pl N => "acerrima",
+        Gen Pl N => "acerrimorum",
+        Dat Pl N => "acerrimis",
+        Akk Pl N => "acerrima",
+        Abl Pl N => "acerrimis",
+        Vok Pl N => "acerrima",
     };
 }
 
@@ -462,33 +462,38 @@ fn test_adverb() {
     assert_eq!(
         Adjektiv::parse(&WörterbuchEintrag::from_one("bonus"))
             .unwrap()
-            .adverb(),
-        "bone"
+            .adverb()
+            .unwrap(),
+        "bene"
     );
     assert_eq!(
         Adjektiv::parse(&WörterbuchEintrag::from_three(
             "pulcher", "pulchra", "pulchrum"
         ))
         .unwrap()
-        .adverb(),
+        .adverb()
+        .unwrap(),
         "pulchre"
     );
     assert_eq!(
         Adjektiv::parse(&WörterbuchEintrag::from_two("fortis", "e"))
             .unwrap()
-            .adverb(),
+            .adverb()
+            .unwrap(),
         "fortiter"
     );
     assert_eq!(
         Adjektiv::parse(&WörterbuchEintrag::from_three("acer", "acris", "acre"))
             .unwrap()
-            .adverb(),
+            .adverb()
+            .unwrap(),
         "acriter"
     );
     assert_eq!(
         Adjektiv::parse(&WörterbuchEintrag::from_two("vehemens", "vehementis"))
             .unwrap()
-            .adverb(),
+            .adverb()
+            .unwrap(),
         "vehementer"
     );
     assert_eq!(
@@ -496,7 +501,8 @@ fn test_adverb() {
             .unwrap()
             .steigern(Steigerung::Superlativ)
             .unwrap()
-            .adverb(),
+            .adverb()
+            .unwrap(),
         "longissime"
     );
     assert_eq!(
@@ -504,7 +510,547 @@ fn test_adverb() {
             .unwrap()
             .steigern(Steigerung::Superlativ)
             .unwrap()
-            .adverb(),
+            .adverb()
+            .unwrap(),
         "vehementissime"
     );
 }
+
+#[test]
+fn test_parse_fehler() {
+    let Err(ParseFehler::KeineDeklination(ablehnungen)) =
+        Adjektiv::parse(&WörterbuchEintrag::from_three("bonus", "bona", "bonus"))
+    else {
+        panic!("bonus, bona, bonus should not parse");
+    };
+    assert_eq!(ablehnungen.len(), 4);
+    assert_eq!(
+        ablehnungen[0].get_grund(),
+        Ablehnungsgrund::DritteFormPasstNicht
+    );
+    assert_eq!(
+        ablehnungen[1].get_grund(),
+        Ablehnungsgrund::ErsteFormPasstNicht
+    );
+    assert_eq!(
+        ablehnungen[2].get_grund(),
+        Ablehnungsgrund::ZweiteFormPasstNicht
+    );
+
+    let Err(ParseFehler::KeineDeklination(ablehnungen)) =
+        Adjektiv::parse(&WörterbuchEintrag::from_two("fortis", "fe"))
+    else {
+        panic!("fortis, fe should not parse");
+    };
+    assert_eq!(
+        ablehnungen[2].get_grund(),
+        Ablehnungsgrund::ZweiteFormPasstNicht
+    );
+    assert_eq!(ablehnungen[2].get_name(), "konsonantische Deklination");
+
+    let fehler = Adjektiv::parse(&WörterbuchEintrag::from_two("fortis", "fe"))
+        .err()
+        .unwrap();
+    assert!(fehler
+        .to_string()
+        .contains("konsonantische Deklination (zweite Form passt nicht)"));
+
+    let Err(ParseFehler::KeineDeklination(ablehnungen)) =
+        Adjektiv::parse(&WörterbuchEintrag::from_three("bonus", "bona", "bonud"))
+    else {
+        panic!("bonus, bona, bonud should not parse");
+    };
+    assert_eq!(ablehnungen[1].get_name(), "pronominale Deklination");
+    assert_eq!(
+        ablehnungen[1].get_grund(),
+        Ablehnungsgrund::ErsteFormPasstNicht
+    );
+}
+
+#[test]
+fn test_klassifikation() {
+    for (eintrag, klasse, stamm) in [
+        (
+            WörterbuchEintrag::from_three("longus", "longa", "longum"),
+            Deklinationsklasse::AODeklination,
+            "long",
+        ),
+        (
+            WörterbuchEintrag::from_two("vehemens", "vehementis"),
+            Deklinationsklasse::KonsonantischeEinendig,
+            "vehement",
+        ),
+        (
+            WörterbuchEintrag::from_two("fortis", "e"),
+            Deklinationsklasse::KonsonantischeZweiendig,
+            "fort",
+        ),
+        (
+            WörterbuchEintrag::from_three("acer", "acris", "acre"),
+            Deklinationsklasse::KonsonantischeDreiendig,
+            "acr",
+        ),
+    ] {
+        let klassifikation = Adjektiv::parse(&eintrag).unwrap().get_klassifikation();
+        assert_eq!(klassifikation.get_klasse(), klasse, "{eintrag:?}");
+        assert_eq!(klassifikation.get_stamm(), stamm, "{eintrag:?}");
+    }
+
+    let komperativ = Adjektiv::parse(&WörterbuchEintrag::from_three("longus", "longa", "longum"))
+        .unwrap()
+        .steigern(Steigerung::Komperativ)
+        .unwrap()
+        .get_klassifikation();
+    assert_eq!(komperativ.get_klasse(), Deklinationsklasse::Komperativ);
+    assert_eq!(komperativ.get_stamm(), "long");
+}
+
+#[test]
+fn test_varianten() {
+    let fortis = Adjektiv::parse(&WörterbuchEintrag::from_two("fortis", "e")).unwrap();
+    assert_eq!(fortis.varianten(M, Pl, Akk), ["fortes", "fortis"]);
+    assert_eq!(fortis.varianten(N, Sg, Abl), ["forti"]);
+
+    let vehemens = Adjektiv::parse(&WörterbuchEintrag::from_two("vehemens", "vehementis")).unwrap();
+    assert_eq!(vehemens.varianten(F, Sg, Abl), ["vehementi", "vehemente"]);
+
+    let longus =
+        Adjektiv::parse(&WörterbuchEintrag::from_three("longus", "longa", "longum")).unwrap();
+    assert_eq!(longus.varianten(M, Pl, Akk), ["longos"]);
+}
+
+#[test]
+fn test_unregelmaessige_steigerung() {
+    for (positiv, komperativ, komperativ_neutrum, superlativ, adverbien) in [
+        (
+            "bonus",
+            "melior",
+            "melius",
+            "optimus",
+            ["bene", "melius", "optime"],
+        ),
+        (
+            "malus",
+            "peior",
+            "peius",
+            "pessimus",
+            ["male", "peius", "pessime"],
+        ),
+        (
+            "magnus",
+            "maior",
+            "maius",
+            "maximus",
+            ["magnopere", "magis", "maxime"],
+        ),
+        (
+            "parvus",
+            "minor",
+            "minus",
+            "minimus",
+            ["paulum", "minus", "minime"],
+        ),
+    ] {
+        let adjektiv = Adjektiv::parse(&WörterbuchEintrag::from_one(positiv)).unwrap();
+        let gesteigert = [
+            Steigerung::Positiv,
+            Steigerung::Komperativ,
+            Steigerung::Superlativ,
+        ]
+        .map(|steigerung| adjektiv.steigern(steigerung).unwrap());
+
+        assert_eq!(gesteigert[1].deklinieren(M, Sg, Nom).unwrap(), komperativ);
+        assert_eq!(
+            gesteigert[1].deklinieren(N, Sg, Nom).unwrap(),
+            komperativ_neutrum
+        );
+        assert_eq!(gesteigert[2].deklinieren(M, Sg, Nom).unwrap(), superlativ);
+        assert_eq!(
+            gesteigert.map(|adjektiv| adjektiv.adverb().unwrap()),
+            adverbien
+        );
+    }
+
+    let multus =
+        Adjektiv::parse(&WörterbuchEintrag::from_three("multus", "multa", "multum")).unwrap();
+    let plus = multus.steigern(Steigerung::Komperativ).unwrap();
+    assert_eq!(plus.deklinieren(N, Sg, Nom).unwrap(), "plus");
+    assert_eq!(plus.deklinieren(N, Sg, Gen).unwrap(), "pluris");
+    assert_eq!(plus.deklinieren(M, Pl, Nom).unwrap(), "plures");
+    assert_eq!(plus.deklinieren(N, Pl, Nom).unwrap(), "plura");
+    assert_eq!(plus.deklinieren(F, Pl, Gen).unwrap(), "plurium");
+    assert_eq!(plus.deklinieren(M, Pl, Dat).unwrap(), "pluribus");
+    let plurimus = multus.steigern(Steigerung::Superlativ).unwrap();
+    assert_eq!(plurimus.deklinieren(F, Sg, Abl).unwrap(), "plurima");
+    assert_eq!(
+        [
+            multus.adverb().unwrap(),
+            plus.adverb().unwrap(),
+            plurimus.adverb().unwrap()
+        ],
+        ["multum", "plus", "plurimum"]
+    );
+
+    let bonus = Adjektiv::parse(&WörterbuchEintrag::from_one("bonus")).unwrap();
+    let optimus = bonus.steigern(Steigerung::Superlativ).unwrap();
+    assert_eq!(optimus.deklinieren(N, Pl, Gen).unwrap(), "optimorum");
+}
+
+#[test]
+fn test_superlativ_rimus_limus() {
+    for (eintrag, superlativ, adverb) in [
+        (
+            WörterbuchEintrag::from_three("pulcher", "pulchra", "pulchrum"),
+            "pulcherrimus",
+            "pulcherrime",
+        ),
+        (
+            WörterbuchEintrag::from_three("miser", "misera", "miserum"),
+            "miserrimus",
+            "miserrime",
+        ),
+        (
+            WörterbuchEintrag::from_three("celer", "celeris", "celere"),
+            "celerrimus",
+            "celerrime",
+        ),
+        (
+            WörterbuchEintrag::from_two("facilis", "e"),
+            "facillimus",
+            "facillime",
+        ),
+        (
+            WörterbuchEintrag::from_two("humilis", "e"),
+            "humillimus",
+            "humillime",
+        ),
+        (
+            WörterbuchEintrag::from_two("utilis", "e"),
+            "utilissimus",
+            "utilissime",
+        ),
+    ] {
+        let adjektiv = Adjektiv::parse(&eintrag)
+            .unwrap()
+            .steigern(Steigerung::Superlativ)
+            .unwrap();
+        assert_eq!(adjektiv.deklinieren(M, Sg, Nom).unwrap(), superlativ);
+        assert_eq!(adjektiv.adverb().unwrap(), adverb);
+    }
+}
+
+#[test]
+fn test_komperativ_varianten() {
+    let melior = Adjektiv::parse(&WörterbuchEintrag::from_one("bonus"))
+        .unwrap()
+        .steigern(Steigerung::Komperativ)
+        .unwrap();
+    assert_eq!(melior.deklinieren(M, Sg, Abl).unwrap(), "meliore");
+    assert_eq!(melior.deklinieren(M, Pl, Gen).unwrap(), "meliorum");
+    assert_eq!(melior.deklinieren(N, Pl, Akk).unwrap(), "meliora");
+    assert_eq!(melior.varianten(F, Sg, Abl), ["meliore", "meliori"]);
+}
+
+#[test]
+fn test_periphrastische_steigerung() {
+    let idoneus = Adjektiv::parse(&WörterbuchEintrag::from_three("idoneus", "a", "um")).unwrap();
+    let komperativ = idoneus.steigern(Steigerung::Komperativ).unwrap();
+    let superlativ = idoneus.steigern(Steigerung::Superlativ).unwrap();
+    assert_eq!(komperativ.deklinieren(M, Sg, Nom).unwrap(), "magis idoneus");
+    assert_eq!(komperativ.deklinieren(F, Pl, Abl).unwrap(), "magis idoneis");
+    assert_eq!(superlativ.deklinieren(N, Sg, Gen).unwrap(), "maxime idonei");
+    assert_eq!(komperativ.adverb().unwrap(), "magis idonee");
+    assert_eq!(superlativ.adverb().unwrap(), "maxime idonee");
+    let komperativ = komperativ.steigern(Steigerung::Komperativ).unwrap();
+    assert_eq!(komperativ.deklinieren(M, Sg, Nom).unwrap(), "magis idoneus");
+    assert!(superlativ.steigern(Steigerung::Komperativ).is_none());
+
+    for positiv in ["arduus", "necessarius", "dubius"] {
+        let adjektiv = Adjektiv::parse(&WörterbuchEintrag::from_one(positiv)).unwrap();
+        let komperativ = adjektiv.steigern(Steigerung::Komperativ).unwrap();
+        assert_eq!(
+            komperativ.deklinieren(M, Sg, Nom).unwrap(),
+            format!("magis {positiv}")
+        );
+    }
+
+    let antiquus = Adjektiv::parse(&WörterbuchEintrag::from_one("antiquus")).unwrap();
+    let komperativ = antiquus.steigern(Steigerung::Komperativ).unwrap();
+    assert_eq!(komperativ.deklinieren(M, Sg, Nom).unwrap(), "antiquior");
+}
+
+#[test]
+fn test_ohne_steigerung() {
+    for eintrag in [
+        WörterbuchEintrag::from_one("meus"),
+        WörterbuchEintrag::from_one("tuus"),
+        WörterbuchEintrag::from_three("noster", "nostra", "nostrum"),
+        WörterbuchEintrag::from_one("ceterus"),
+        WörterbuchEintrag::from_one("medius"),
+    ] {
+        let adjektiv = Adjektiv::parse(&eintrag).unwrap();
+        assert!(
+            adjektiv.steigern(Steigerung::Positiv).is_some(),
+            "{eintrag:?}"
+        );
+        assert!(
+            adjektiv.steigern(Steigerung::Komperativ).is_none(),
+            "{eintrag:?}"
+        );
+        assert!(
+            adjektiv.steigern(Steigerung::Superlativ).is_none(),
+            "{eintrag:?}"
+        );
+        assert!(
+            adjektiv.steigerungen(Steigerung::Superlativ).is_empty(),
+            "{eintrag:?}"
+        );
+    }
+
+    let longus = Adjektiv::parse(&WörterbuchEintrag::from_one("longus")).unwrap();
+    assert!(longus.steigern(Steigerung::Komperativ).is_some());
+}
+
+#[test]
+fn test_pronominal() {
+    test_deklination! {
+        WörterbuchEintrag::from_three("solus", "sola", "solum");
+
+        Nom Sg M => "solus",
+        Gen Sg M => "solius",
+        Dat Sg M => "soli",
+        Akk Sg M => "solum",
+        Abl Sg M => "solo",
+        Gen Sg F => "solius",
+        Dat Sg F => "soli",
+        Abl Sg F => "sola",
+        Gen Sg N => "solius",
+        Dat Sg N => "soli",
+        Nom Pl M => "soli",
+        Gen Pl F => "solarum",
+        Dat Pl N => "solis",
+    };
+
+    test_deklination! {
+        WörterbuchEintrag::from_three("alius", "alia", "aliud");
+
+        Nom Sg N => "aliud",
+        Akk Sg N => "aliud",
+        Gen Sg F => "alterius",
+        Dat Sg M => "alii",
+        Nom Pl N => "alia",
+    };
+
+    test_deklination! {
+        WörterbuchEintrag::from_three("alter", "altera", "alterum");
+
+        Nom Sg M => "alter",
+        Gen Sg M => "alterius",
+        Dat Sg F => "alteri",
+        Akk Sg F => "alteram",
+    };
+
+    let alius = Adjektiv::parse(&WörterbuchEintrag::from_three("alius", "alia", "aliud")).unwrap();
+    assert_eq!(alius.varianten(M, Sg, Gen), ["alterius", "alius"]);
+    assert!(alius.steigern(Steigerung::Komperativ).is_none());
+
+    let eintrag = WörterbuchEintrag::from_three("alteruter", "alterutra", "alterutrum");
+    let alteruter = Adjektiv::parse(&eintrag).unwrap();
+    assert_eq!(alteruter.deklinieren(M, Sg, Gen).unwrap(), "alterutri");
+    let alteruter = Adjektiv::parse(&eintrag.pronominal()).unwrap();
+    assert_eq!(alteruter.deklinieren(M, Sg, Gen).unwrap(), "alterutrius");
+}
+
+#[test]
+fn test_konsonantenstamm() {
+    test_deklination! {
+        WörterbuchEintrag::from_two("vetus", "veteris");
+
+        Nom Sg M => "vetus",
+        Gen Sg M => "veteris",
+        Dat Sg M => "veteri",
+        Akk Sg M => "veterem",
+        Abl Sg M => "vetere",
+        Nom Pl M => "veteres",
+        Gen Pl M => "veterum",
+        Akk Pl F => "veteres",
+        Nom Sg N => "vetus",
+        Akk Sg N => "vetus",
+        Abl Sg N => "vetere",
+        Nom Pl N => "vetera",
+        Gen Pl N => "veterum",
+        Akk Pl N => "vetera",
+        Dat Pl N => "veteribus",
+    };
+
+    test_deklination! {
+        WörterbuchEintrag::from_two("princeps", "principis");
+
+        Abl Sg F => "principe",
+        Gen Pl M => "principum",
+        Nom Pl N => "principa",
+    };
+
+    let dives = Adjektiv::parse(&WörterbuchEintrag::from_two("dives", "divitis")).unwrap();
+    assert_eq!(dives.varianten(M, Pl, Akk), ["divites"]);
+    assert_eq!(
+        dives.get_klassifikation().get_klasse(),
+        Deklinationsklasse::Konsonantenstamm
+    );
+}
+
+#[test]
+fn test_ohne_positiv() {
+    test_deklination! {
+        WörterbuchEintrag::from_two("prior", "prius");
+
+        Nom Sg M => "prior",
+        Gen Sg F => "prioris",
+        Abl Sg M => "priore",
+        Nom Sg N => "prius",
+        Nom Pl N => "priora",
+        Gen Pl M => "priorum",
+    };
+
+    for (eintrag, superlativ) in [
+        (WörterbuchEintrag::from_two("prior", "prius"), "primus"),
+        (WörterbuchEintrag::from_two("citerior", "ius"), "citimus"),
+        (
+            WörterbuchEintrag::from_two("ulterior", "ulterius"),
+            "ultimus",
+        ),
+    ] {
+        let komperativ = Adjektiv::parse(&eintrag).unwrap();
+        assert!(komperativ.steigern(Steigerung::Positiv).is_none());
+        let komperativ_adjektiv = komperativ.steigern(Steigerung::Komperativ).unwrap();
+        assert_eq!(
+            komperativ_adjektiv.deklinieren(M, Sg, Nom),
+            komperativ.deklinieren(M, Sg, Nom)
+        );
+        assert_eq!(
+            komperativ_adjektiv.get_klassifikation().get_klasse(),
+            Deklinationsklasse::Komperativ
+        );
+        let superlativ_adjektiv = komperativ.steigern(Steigerung::Superlativ).unwrap();
+        assert_eq!(
+            superlativ_adjektiv.deklinieren(M, Sg, Nom).unwrap(),
+            superlativ
+        );
+        let superlativ_adjektiv = superlativ_adjektiv
+            .steigern(Steigerung::Superlativ)
+            .unwrap();
+        assert_eq!(
+            superlativ_adjektiv.deklinieren(M, Sg, Nom).unwrap(),
+            superlativ
+        );
+    }
+
+    let prior = Adjektiv::parse(&WörterbuchEintrag::from_two("prior", "prius")).unwrap();
+    let primus = prior.steigern(Steigerung::Superlativ).unwrap();
+    assert_eq!(primus.deklinieren(F, Pl, Dat).unwrap(), "primis");
+    assert_eq!(
+        [prior.adverb().unwrap(), primus.adverb().unwrap()],
+        ["prius", "primo"]
+    );
+}
+
+#[test]
+fn test_mehrere_superlative() {
+    for (positiv, komperativ, superlative) in [
+        ("superus", "superior", &["summus", "supremus"]),
+        ("inferus", "inferior", &["infimus", "imus"]),
+        ("posterus", "posterior", &["postremus", "postumus"]),
+        ("exterus", "exterior", &["extremus", "extimus"]),
+    ] {
+        let adjektiv = Adjektiv::parse(&WörterbuchEintrag::from_one(positiv)).unwrap();
+        let komperativ_adjektiv = adjektiv.steigern(Steigerung::Komperativ).unwrap();
+        assert_eq!(
+            komperativ_adjektiv.deklinieren(M, Sg, Nom).unwrap(),
+            komperativ
+        );
+
+        let formen = adjektiv
+            .steigerungen(Steigerung::Superlativ)
+            .iter()
+            .map(|superlativ| superlativ.deklinieren(M, Sg, Nom).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(formen, superlative);
+        assert_eq!(
+            adjektiv
+                .steigern(Steigerung::Superlativ)
+                .unwrap()
+                .deklinieren(M, Sg, Nom)
+                .unwrap(),
+            superlative[0]
+        );
+    }
+
+    let longus = Adjektiv::parse(&WörterbuchEintrag::from_one("longus")).unwrap();
+    assert_eq!(longus.steigerungen(Steigerung::Superlativ).len(), 1);
+}
+
+#[test]
+fn test_fehlende_formen() {
+    let plus = Adjektiv::parse(&WörterbuchEintrag::from_one("multus"))
+        .unwrap()
+        .steigern(Steigerung::Komperativ)
+        .unwrap();
+    assert_eq!(plus.deklinieren(M, Sg, Nom), None);
+    assert_eq!(plus.deklinieren(F, Sg, Gen), None);
+    assert!(plus.varianten(F, Sg, Abl).is_empty());
+    assert_eq!(plus.deklinieren(N, Sg, Gen).as_deref(), Some("pluris"));
+
+    let acer = Adjektiv::parse(&WörterbuchEintrag::from_three("acer", "acris", "acre")).unwrap();
+    for genus in Genus::ALLE {
+        for numerus in Numerus::ALLE {
+            for kasus in Kasus::ALLE {
+                assert!(acer.deklinieren(genus, numerus, kasus).is_some());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_adverb_ausnahmen() {
+    for (eintrag, adverb) in [
+        (WörterbuchEintrag::from_one("subitus"), Some("subito")),
+        (WörterbuchEintrag::from_one("tutus"), Some("tuto")),
+        (WörterbuchEintrag::from_one("primus"), Some("primo")),
+        (WörterbuchEintrag::from_two("facilis", "e"), Some("facile")),
+        (WörterbuchEintrag::from_one("multus"), Some("multum")),
+        (WörterbuchEintrag::from_one("ceterus"), Some("ceterum")),
+        (WörterbuchEintrag::from_one("solus"), Some("solum")),
+        (WörterbuchEintrag::from_one("bonus"), Some("bene")),
+        (WörterbuchEintrag::from_one("malus"), Some("male")),
+        (
+            WörterbuchEintrag::from_two("audax", "audacis"),
+            Some("audacter"),
+        ),
+        (
+            WörterbuchEintrag::from_three("alius", "alia", "aliud"),
+            Some("aliter"),
+        ),
+        (WörterbuchEintrag::from_one("longus"), Some("longe")),
+        (WörterbuchEintrag::from_one("unus"), None),
+        (WörterbuchEintrag::from_one("totus"), None),
+        (WörterbuchEintrag::from_two("vetus", "veteris"), None),
+    ] {
+        let adjektiv = Adjektiv::parse(&eintrag).unwrap();
+        assert_eq!(adjektiv.adverb().as_deref(), adverb, "{eintrag:?}");
+    }
+
+    // primus als eigener Eintrag und als Superlativ von prior
+    let primus = Adjektiv::parse(&WörterbuchEintrag::from_one("primus")).unwrap();
+    let prior = Adjektiv::parse(&WörterbuchEintrag::from_two("prior", "prius")).unwrap();
+    assert_eq!(
+        primus.adverb(),
+        prior.steigern(Steigerung::Superlativ).unwrap().adverb()
+    );
+    for adjektiv in [primus, prior.steigern(Steigerung::Superlativ).unwrap()] {
+        assert_eq!(adjektiv.adverb_varianten(), ["primo", "primum"]);
+    }
+    let longus = Adjektiv::parse(&WörterbuchEintrag::from_one("longus")).unwrap();
+    assert_eq!(longus.adverb_varianten(), ["longe"]);
+    let unus = Adjektiv::parse(&WörterbuchEintrag::from_one("unus")).unwrap();
+    assert!(unus.adverb_varianten().is_empty());
+}
@@ -0,0 +1,210 @@
+// Komperativ aus Stamm und Erweiterung, zB mel + ior/ius oder min + or/us
+pub struct Komperativ {
+    pub stamm: &'static str,
+    pub erweiterung: &'static str,
+    pub endung_neutrum: &'static str,
+    pub adverb: &'static str,
+    pub nur_neutrum_singular: bool, // plus, pluris; im Plural plures, plura
+}
+
+pub struct Superlativ {
+    pub stamm: &'static str,
+    pub adverb: &'static str,
+}
+
+pub struct UnregelmäßigeSteigerung {
+    positiv_stamm: &'static str,
+    pub adverb: &'static str,
+    pub komperativ: Komperativ,
+    pub superlativ: Superlativ,
+}
+
+static STEIGERUNGEN: [UnregelmäßigeSteigerung; 5] = [
+    UnregelmäßigeSteigerung {
+        positiv_stamm: "bon",
+        adverb: "bene",
+        komperativ: Komperativ {
+            stamm: "mel",
+            erweiterung: "ior",
+            endung_neutrum: "ius",
+            adverb: "melius",
+            nur_neutrum_singular: false,
+        },
+        superlativ: Superlativ {
+            stamm: "optim",
+            adverb: "optime",
+        },
+    },
+    UnregelmäßigeSteigerung {
+        positiv_stamm: "mal",
+        adverb: "male",
+        komperativ: Komperativ {
+            stamm: "pe",
+            erweiterung: "ior",
+            endung_neutrum: "ius",
+            adverb: "peius",
+            nur_neutrum_singular: false,
+        },
+        superlativ: Superlativ {
+            stamm: "pessim",
+            adverb: "pessime",
+        },
+    },
+    UnregelmäßigeSteigerung {
+        positiv_stamm: "magn",
+        adverb: "magnopere",
+        komperativ: Komperativ {
+            stamm: "mai",
+            erweiterung: "or",
+            endung_neutrum: "us",
+            adverb: "magis",
+            nur_neutrum_singular: false,
+        },
+        superlativ: Superlativ {
+            stamm: "maxim",
+            adverb: "maxime",
+        },
+    },
+    UnregelmäßigeSteigerung {
+        positiv_stamm: "parv",
+        adverb: "paulum",
+        komperativ: Komperativ {
+            stamm: "min",
+            erweiterung: "or",
+            endung_neutrum: "us",
+            adverb: "minus",
+            nur_neutrum_singular: false,
+        },
+        superlativ: Superlativ {
+            stamm: "minim",
+            adverb: "minime",
+        },
+    },
+    UnregelmäßigeSteigerung {
+        positiv_stamm: "mult",
+        adverb: "multum",
+        komperativ: Komperativ {
+            stamm: "pl",
+            erweiterung: "ur",
+            endung_neutrum: "us",
+            adverb: "plus",
+            nur_neutrum_singular: true,
+        },
+        superlativ: Superlativ {
+            stamm: "plurim",
+            adverb: "plurimum",
+        },
+    },
+];
+
+impl UnregelmäßigeSteigerung {
+    pub fn finden(positiv_stamm: &str) -> Option<&'static Self> {
+        STEIGERUNGEN
+            .iter()
+            .find(|steigerung| steigerung.positiv_stamm == positiv_stamm)
+    }
+}
+
+// Superlative, die sich nicht aus dem Stamm des Komperativs bilden lassen, bevorzugte Form zuerst
+pub struct Superlative {
+    komperativ_stamm: &'static str,
+    pub superlative: &'static [Superlativ],
+}
+
+static SUPERLATIVE: [Superlative; 9] = [
+    Superlative {
+        komperativ_stamm: "pr",
+        superlative: &[Superlativ {
+            stamm: "prim",
+            adverb: "primo",
+        }],
+    },
+    Superlative {
+        komperativ_stamm: "citer",
+        superlative: &[Superlativ {
+            stamm: "citim",
+            adverb: "citime",
+        }],
+    },
+    Superlative {
+        komperativ_stamm: "ulter",
+        superlative: &[Superlativ {
+            stamm: "ultim",
+            adverb: "ultimum",
+        }],
+    },
+    Superlative {
+        komperativ_stamm: "inter",
+        superlative: &[Superlativ {
+            stamm: "intim",
+            adverb: "intime",
+        }],
+    },
+    Superlative {
+        komperativ_stamm: "deter",
+        superlative: &[Superlativ {
+            stamm: "deterrim",
+            adverb: "deterrime",
+        }],
+    },
+    Superlative {
+        komperativ_stamm: "exter",
+        superlative: &[
+            Superlativ {
+                stamm: "extrem",
+                adverb: "extreme",
+            },
+            Superlativ {
+                stamm: "extim",
+                adverb: "extime",
+            },
+        ],
+    },
+    Superlative {
+        komperativ_stamm: "super",
+        superlative: &[
+            Superlativ {
+                stamm: "summ",
+                adverb: "summe",
+            },
+            Superlativ {
+                stamm: "suprem",
+                adverb: "supreme",
+            },
+        ],
+    },
+    Superlative {
+        komperativ_stamm: "infer",
+        superlative: &[
+            Superlativ {
+                stamm: "infim",
+                adverb: "infime",
+            },
+            Superlativ {
+                stamm: "im",
+                adverb: "ime",
+            },
+        ],
+    },
+    Superlative {
+        komperativ_stamm: "poster",
+        superlative: &[
+            Superlativ {
+                stamm: "postrem",
+                adverb: "postremo",
+            },
+            Superlativ {
+                stamm: "postum",
+                adverb: "postume",
+            },
+        ],
+    },
+];
+
+impl Superlative {
+    pub fn finden(komperativ_stamm: &str) -> Option<&'static Self> {
+        SUPERLATIVE
+            .iter()
+            .find(|superlative| superlative.komperativ_stamm == komperativ_stamm)
+    }
+}
@@ -19,7 +19,9 @@ fn main() {
         for genus in Genus::ALLE {
             for numerus in Numerus::ALLE {
                 for kasus in Kasus::ALLE {
-                    result.push(adjektiv.deklinieren(genus, numerus, kasus));
+                    if let Some(form) = adjektiv.deklinieren(genus, numerus, kasus) {
+                        result.push(form);
+                    }
                 }
             }
         }
@@ -14,10 +14,11 @@ fn main() {
     let start = Instant::now();
 
     for _ in 1..=ITERATIONS {
-        let Some(nomen) = Nomen::parse(&WörterbuchEintrag {
+        let Ok(nomen) = Nomen::parse(&WörterbuchEintrag {
             nominativ: "senator",
             genitiv: Some("senatoris"),
             genus: Some(Genus::Maskulinum),
+            ortsname: false,
         }) else {
             panic!("invalid arguments");
         };
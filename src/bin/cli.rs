@@ -2,7 +2,7 @@ use std::io::stdin;
 
 use latein_rs::{
     grammatik::{Genus, Kasus, Numerus},
-    nomen::{Nomen, WörterbuchEintrag},
+    nomen::{Nomen, ParseFehler, WörterbuchEintrag},
 };
 
 fn main() {
@@ -30,14 +30,14 @@ fn main() {
             let mut input = String::new();
             stdin().read_line(&mut input).unwrap();
             println!();
-            if input.trim().len() == 0 {
+            if input.trim().is_empty() {
                 break 'genus None;
             }
             if input.trim().len() != 1 {
                 println!("only one letter allowed");
                 continue 'main;
             }
-            let letter = input.trim().chars().nth(0).unwrap();
+            let letter = input.trim().chars().next().unwrap();
             for genus in Genus::ALLE {
                 if letter == genus.get_letter() {
                     break 'genus Some(genus);
@@ -49,34 +49,55 @@ fn main() {
 
         let eintrag = WörterbuchEintrag {
             nominativ: &nominativ_singular,
-            genitiv: match genitiv_singular {
-                Some(ref genitiv_singular) => Some(genitiv_singular),
-                None => None,
-            },
+            genitiv: genitiv_singular.as_deref(),
             genus,
+            ortsname: false,
         };
-        let Some(nomen) = Nomen::parse(&eintrag) else {
-            println!("invalid arguments");
-            continue;
+        let nomen = match Nomen::parse(&eintrag) {
+            Ok(nomen) => nomen,
+            Err(ParseFehler::KeineDeklination(ablehnungen)) => {
+                println!("Keine passende Deklination:");
+                for ablehnung in ablehnungen {
+                    println!("{ablehnung}");
+                }
+                println!();
+                continue;
+            }
+            Err(ParseFehler::Mehrdeutig(mut kandidaten)) => {
+                println!("Mehrdeutiger Eintrag:");
+                for (i, kandidat) in kandidaten.iter().enumerate() {
+                    println!(
+                        "{}: {} ({:?})",
+                        i + 1,
+                        kandidat.get_grund(),
+                        kandidat.get_genus()
+                    );
+                }
+                let mut input = String::new();
+                stdin().read_line(&mut input).unwrap();
+                println!();
+                match input.trim().parse::<usize>() {
+                    Ok(auswahl) if (1..=kandidaten.len()).contains(&auswahl) => {
+                        kandidaten.swap_remove(auswahl - 1).into_nomen()
+                    }
+                    _ => {
+                        println!("invalid choice");
+                        continue;
+                    }
+                }
+            }
         };
 
         println!("Geschlecht: {:?}", nomen.get_genus());
         println!();
         for numerus in Numerus::ALLE {
             for kasus in Kasus::ALLE {
-                let form = nomen.deklinieren(numerus, kasus);
-                println!(
-                    "{:?} {:?} => {}",
-                    kasus,
-                    numerus,
-                    match form {
-                        Some(form) => form,
-                        None => {
-                            println!("-");
-                            continue;
-                        }
-                    }
-                );
+                let varianten = nomen.varianten(numerus, kasus);
+                if varianten.is_empty() {
+                    println!("-");
+                    continue;
+                }
+                println!("{:?} {:?} => {}", kasus, numerus, varianten.join(", "));
             }
             println!();
         }
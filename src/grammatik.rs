@@ -1,7 +1,70 @@
+use std::fmt;
+
 pub fn test_form(form: &str, stamm: &str, endung: &str) -> bool {
     form.starts_with(stamm) && form.ends_with(endung) && form.len() == stamm.len() + endung.len()
 }
 
+pub fn ist_vokal(buchstabe: char) -> bool {
+    matches!(
+        buchstabe.to_ascii_lowercase(),
+        'a' | 'e' | 'i' | 'o' | 'u' | 'y'
+    )
+}
+
+// Zählt die Vokalgruppen eines Wortes, was für den Vergleich von Wortformen genau genug ist
+pub fn silben(wort: &str) -> usize {
+    let mut silben = 0;
+    let mut vorher_vokal = false;
+    for buchstabe in wort.chars() {
+        let vokal = ist_vokal(buchstabe);
+        if vokal && !vorher_vokal {
+            silben += 1;
+        }
+        vorher_vokal = vokal;
+    }
+    silben
+}
+
+// Eine Deklination oder Konjugation, die einen Wörterbucheintrag abgelehnt hat. G ist der
+// Ablehnungsgrund des jeweiligen Moduls
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Ablehnung<G> {
+    name: &'static str,
+    grund: G,
+}
+
+impl<G: Copy> Ablehnung<G> {
+    pub(crate) fn new(name: &'static str, grund: G) -> Self {
+        Self { name, grund }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn get_grund(&self) -> G {
+        self.grund
+    }
+}
+
+impl<G: fmt::Display> Ablehnung<G> {
+    pub(crate) fn fmt_liste(ablehnungen: &[Self], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, ablehnung) in ablehnungen.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{ablehnung}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<G: fmt::Display> fmt::Display for Ablehnung<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.name, self.grund)
+    }
+}
+
 #[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
 pub enum Kasus {
     Nominativ,
@@ -10,6 +73,8 @@ pub enum Kasus {
     Akkusativ,
     Ablativ,
     Vokativ,
+    // Nicht in ALLE enthalten, da nur wenige Nomen einen Lokativ bilden
+    Lokativ,
 }
 
 impl Kasus {
@@ -58,3 +123,56 @@ pub enum Steigerung {
     Komperativ,
     Superlativ,
 }
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub enum Person {
+    Erste,
+    Zweite,
+    Dritte,
+}
+
+impl Person {
+    pub const ALLE: [Self; 3] = [Self::Erste, Self::Zweite, Self::Dritte];
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub enum Tempus {
+    Präsens,
+    Imperfekt,
+    Futur,
+    Perfekt,
+    Plusquamperfekt,
+    FuturII,
+}
+
+impl Tempus {
+    pub const ALLE: [Self; 6] = [
+        Self::Präsens,
+        Self::Imperfekt,
+        Self::Futur,
+        Self::Perfekt,
+        Self::Plusquamperfekt,
+        Self::FuturII,
+    ];
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub enum GenusVerbi {
+    Aktiv,
+    Passiv,
+}
+
+impl GenusVerbi {
+    pub const ALLE: [Self; 2] = [Self::Aktiv, Self::Passiv];
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub enum Modus {
+    Indikativ,
+    Konjunktiv,
+    Imperativ, // Präsens für den Imperativ I, Futur für den Imperativ II
+}
+
+impl Modus {
+    pub const ALLE: [Self; 3] = [Self::Indikativ, Self::Konjunktiv, Self::Imperativ];
+}
@@ -1,3 +1,5 @@
 pub mod adjektiv;
 pub mod grammatik;
 pub mod nomen;
+pub mod verb;
+pub mod wortgruppe;
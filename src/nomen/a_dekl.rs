@@ -1,6 +1,9 @@
 use crate::grammatik::{Genus, Kasus, Numerus};
 
-use super::StammDeklination;
+use super::{Deklinationsklasse, StammDeklination};
+
+// Stämme mit Dativ und Ablativ Plural auf -abus zur Unterscheidung von der o-Deklination
+const ABUS_STÄMME: [&str; 3] = ["fili", "de", "libert"];
 
 pub struct ADeklination<'a> {
     stamm: &'a str,
@@ -8,6 +11,8 @@ pub struct ADeklination<'a> {
 }
 
 impl<'a> StammDeklination<'a> for ADeklination<'a> {
+    const KLASSE: Deklinationsklasse = Deklinationsklasse::ADeklination;
+    const GRUND: &'static str = "a-Deklination: Nominativ auf -a";
     const DEFAULT_GENUS: Option<Genus> = Some(Genus::Femininum);
     const ALLOWS_FEMININUM: bool = true;
     const ALLOWS_MASKULINUM: bool = true;
@@ -33,6 +38,7 @@ impl<'a> StammDeklination<'a> for ADeklination<'a> {
                 Kasus::Dativ => "ae",
                 Kasus::Akkusativ => "am",
                 Kasus::Ablativ => "a",
+                Kasus::Lokativ => "ae",
             },
             Numerus::Plural => match kasus {
                 Kasus::Nominativ | Kasus::Vokativ => "ae",
@@ -40,7 +46,19 @@ impl<'a> StammDeklination<'a> for ADeklination<'a> {
                 Kasus::Dativ => "is",
                 Kasus::Akkusativ => "as",
                 Kasus::Ablativ => "is",
+                Kasus::Lokativ => "is",
             },
         })
     }
+
+    fn get_varianten(&self, numerus: Numerus, kasus: Kasus) -> &'static [&'static str] {
+        match (numerus, kasus) {
+            (Numerus::Plural, Kasus::Dativ | Kasus::Ablativ)
+                if ABUS_STÄMME.contains(&self.stamm) =>
+            {
+                &["abus"]
+            }
+            _ => &[],
+        }
+    }
 }
@@ -0,0 +1,109 @@
+use crate::grammatik::{test_form, Genus, Kasus, Numerus};
+
+use super::{
+    a_dekl::ADeklination, Ablehnungsgrund, Deklination, Deklinationsklasse, Klassifikation,
+    ParsableDeklination, StammDeklination, WörterbuchEintrag,
+};
+
+#[derive(Clone, Copy)]
+enum Typ {
+    // Aeneas, Aeneae
+    As,
+    // Anchises, Anchisae
+    Es,
+    // epitome, epitomes
+    E,
+}
+
+pub struct GriechischeADeklination<'a> {
+    stamm: &'a str,
+    typ: Typ,
+}
+
+impl<'a> Deklination for GriechischeADeklination<'a> {
+    fn deklinieren(&self, numerus: Numerus, kasus: Kasus) -> Option<String> {
+        let endung = match (numerus, self.typ) {
+            (Numerus::Plural, _) => ADeklination::get_endung(numerus, kasus)?,
+            (Numerus::Singular, Typ::As) => match kasus {
+                Kasus::Nominativ => "as",
+                Kasus::Genitiv | Kasus::Dativ | Kasus::Lokativ => "ae",
+                Kasus::Akkusativ => "an",
+                Kasus::Ablativ | Kasus::Vokativ => "a",
+            },
+            (Numerus::Singular, Typ::Es) => match kasus {
+                Kasus::Nominativ => "es",
+                Kasus::Genitiv | Kasus::Dativ | Kasus::Lokativ => "ae",
+                Kasus::Akkusativ => "en",
+                Kasus::Ablativ | Kasus::Vokativ => "e",
+            },
+            (Numerus::Singular, Typ::E) => match kasus {
+                Kasus::Nominativ | Kasus::Ablativ | Kasus::Vokativ => "e",
+                Kasus::Genitiv => "es",
+                Kasus::Dativ | Kasus::Lokativ => "ae",
+                Kasus::Akkusativ => "en",
+            },
+        };
+
+        let mut result = String::with_capacity(self.stamm.len() + endung.len());
+        result.push_str(self.stamm);
+        result.push_str(endung);
+        Some(result)
+    }
+
+    fn klassifikation(&self) -> Klassifikation<'_> {
+        Klassifikation {
+            klasse: Deklinationsklasse::GriechischeADeklination,
+            stamm: self.stamm,
+            plural: false,
+        }
+    }
+}
+
+impl<'a> ParsableDeklination<'a> for GriechischeADeklination<'a> {
+    const GRUND: &'static str = "griechische a-Deklination: Nominativ auf -as, -es oder -e";
+    const DEFAULT_GENUS: Option<Genus> = None;
+    const ALLOWS_FEMININUM: bool = true;
+    const ALLOWS_MASKULINUM: bool = true;
+    const ALLOWS_NEUTRUM: bool = false;
+
+    fn parse_wörterbuch_formen(
+        nominativ: &'a str,
+        genitiv: Option<&'a str>,
+    ) -> Result<Self, Ablehnungsgrund> {
+        // Erst der Genitiv zeigt, dass das Nomen griechisch dekliniert wird
+        let genitiv = genitiv.ok_or(Ablehnungsgrund::GenitivFehlt)?;
+
+        let (stamm, typ, genitiv_endung) = if let Some(stamm) = nominativ.strip_suffix("as") {
+            (stamm, Typ::As, "ae")
+        } else if let Some(stamm) = nominativ.strip_suffix("es") {
+            (stamm, Typ::Es, "ae")
+        } else if let Some(stamm) = nominativ.strip_suffix('e') {
+            (stamm, Typ::E, "es")
+        } else {
+            return Err(Ablehnungsgrund::NominativPasstNicht);
+        };
+
+        if !test_form(genitiv, stamm, genitiv_endung) {
+            return Err(Ablehnungsgrund::GenitivPasstNicht);
+        }
+
+        Ok(Self { stamm, typ })
+    }
+
+    fn parse_wörterbuch_eintrag(
+        eintrag: &WörterbuchEintrag<'a>,
+    ) -> Result<(Genus, Self), Ablehnungsgrund> {
+        let deklination = Self::parse_wörterbuch_formen(eintrag.nominativ, eintrag.genitiv)?;
+
+        let genus = match deklination.typ {
+            Typ::As | Typ::Es => Genus::Maskulinum,
+            Typ::E => Genus::Femininum,
+        };
+        match eintrag.genus {
+            Some(eintrag_genus) if eintrag_genus != genus => {
+                Err(Ablehnungsgrund::GenusNichtErlaubt(eintrag_genus))
+            }
+            _ => Ok((genus, deklination)),
+        }
+    }
+}
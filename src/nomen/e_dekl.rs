@@ -1,6 +1,6 @@
 use crate::grammatik::{Genus, Kasus, Numerus};
 
-use super::StammDeklination;
+use super::{Deklinationsklasse, StammDeklination};
 
 pub struct EDeklination<'a> {
     stamm: &'a str,
@@ -8,6 +8,8 @@ pub struct EDeklination<'a> {
 }
 
 impl<'a> StammDeklination<'a> for EDeklination<'a> {
+    const KLASSE: Deklinationsklasse = Deklinationsklasse::EDeklination;
+    const GRUND: &'static str = "e-Deklination: Nominativ auf -es";
     const DEFAULT_GENUS: Option<Genus> = Some(Genus::Femininum);
     const ALLOWS_FEMININUM: bool = true;
     const ALLOWS_MASKULINUM: bool = true;
@@ -33,6 +35,7 @@ impl<'a> StammDeklination<'a> for EDeklination<'a> {
                 Kasus::Dativ => "ei",
                 Kasus::Akkusativ => "em",
                 Kasus::Ablativ => "e",
+                Kasus::Lokativ => "e",
             },
             Numerus::Plural => match kasus {
                 Kasus::Nominativ | Kasus::Vokativ => "es",
@@ -40,6 +43,7 @@ impl<'a> StammDeklination<'a> for EDeklination<'a> {
                 Kasus::Dativ => "ebus",
                 Kasus::Akkusativ => "es",
                 Kasus::Ablativ => "ebus",
+                Kasus::Lokativ => "ebus",
             },
         })
     }
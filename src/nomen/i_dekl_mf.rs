@@ -0,0 +1,154 @@
+use crate::grammatik::{ist_vokal, silben, test_form, Genus, Kasus, Numerus};
+
+use super::{
+    Ablehnungsgrund, Deklination, Deklinationsklasse, Klassifikation, ParsableDeklination,
+};
+
+// Reine i-Stämme mit Akkusativ -im und Ablativ -i
+const REINE_I_STÄMME: [&str; 7] = [
+    "turris", "sitis", "tussis", "securis", "puppis", "febris", "Tiberis",
+];
+
+// Gleichsilbige Nomen, die trotzdem konsonantisch dekliniert werden
+const KONSONANTISCHE_AUSNAHMEN: [&str; 4] = ["canis", "iuvenis", "vates", "sedes"];
+
+// Gleichsilbige Nomen auf -er mit Genitiv -ris, die anders als pater, patris i-Stämme sind
+const I_STÄMME_AUF_ER: [&str; 4] = ["imber", "linter", "venter", "uter"];
+
+pub struct IDeklinationMaskulinumFemininum<'a> {
+    nominativ_singular: Option<&'a str>,
+    stamm: &'a str,
+    plural: bool,
+    rein: bool,
+}
+
+impl<'a> IDeklinationMaskulinumFemininum<'a> {
+    // civis, civis; nubes, nubis; urbs, urbis; cohors, cohortis
+    fn ist_i_stamm(nominativ: &str, genitiv: &str, stamm: &str) -> bool {
+        if KONSONANTISCHE_AUSNAHMEN.contains(&nominativ) {
+            return false;
+        }
+        if I_STÄMME_AUF_ER.contains(&nominativ) {
+            return true;
+        }
+
+        let gleichsilbig = (nominativ.ends_with("is") || nominativ.ends_with("es"))
+            && silben(nominativ) == silben(genitiv);
+
+        let mut stamm_ende = stamm.chars().rev();
+        let zwei_konsonanten = matches!(
+            (stamm_ende.next(), stamm_ende.next()),
+            (Some(letzter), Some(vorletzter)) if !ist_vokal(letzter) && !ist_vokal(vorletzter)
+        );
+
+        let nt_rt_stamm = (nominativ.ends_with("ns") || nominativ.ends_with("rs"))
+            && (stamm.ends_with("nt") || stamm.ends_with("rt"));
+
+        gleichsilbig || (zwei_konsonanten && silben(nominativ) == 1) || nt_rt_stamm
+    }
+}
+
+impl<'a> IDeklinationMaskulinumFemininum<'a> {
+    // parens, parentium/parentum
+    fn ist_partizipartig(&self) -> bool {
+        self.nominativ_singular.is_some_and(|nominativ| {
+            silben(nominativ) > 1 && (nominativ.ends_with("ns") || nominativ.ends_with("rs"))
+        })
+    }
+
+    // Die bevorzugte Endung zuerst
+    fn get_endungen(&self, numerus: Numerus, kasus: Kasus) -> &'static [&'static str] {
+        match numerus {
+            Numerus::Singular => match kasus {
+                // Der Nominativ Singular lässt sich nicht aus dem Stamm bilden
+                Kasus::Nominativ | Kasus::Vokativ => &[],
+                Kasus::Genitiv => &["is"],
+                Kasus::Dativ => &["i"],
+                Kasus::Akkusativ if self.rein => &["im", "em"],
+                Kasus::Akkusativ => &["em"],
+                Kasus::Ablativ | Kasus::Lokativ if self.rein => &["i", "e"],
+                Kasus::Ablativ | Kasus::Lokativ => &["e"],
+            },
+            Numerus::Plural => match kasus {
+                Kasus::Nominativ | Kasus::Vokativ => &["es"],
+                Kasus::Genitiv if self.ist_partizipartig() => &["ium", "um"],
+                Kasus::Genitiv => &["ium"],
+                Kasus::Dativ => &["ibus"],
+                Kasus::Akkusativ => &["es", "is"],
+                Kasus::Ablativ => &["ibus"],
+                Kasus::Lokativ => &["ibus"],
+            },
+        }
+    }
+}
+
+impl<'a> Deklination for IDeklinationMaskulinumFemininum<'a> {
+    fn deklinieren(&self, numerus: Numerus, kasus: Kasus) -> Option<String> {
+        self.varianten(numerus, kasus).into_iter().next()
+    }
+
+    fn varianten(&self, numerus: Numerus, kasus: Kasus) -> Vec<String> {
+        if self.plural && matches!(numerus, Numerus::Singular) {
+            return Vec::new();
+        }
+
+        if let (Numerus::Singular, Kasus::Nominativ | Kasus::Vokativ) = (numerus, kasus) {
+            return vec![String::from(self.nominativ_singular.unwrap())];
+        }
+
+        self.get_endungen(numerus, kasus)
+            .iter()
+            .map(|endung| [self.stamm, endung].concat())
+            .collect()
+    }
+
+    fn klassifikation(&self) -> Klassifikation<'_> {
+        Klassifikation {
+            klasse: Deklinationsklasse::IDeklination,
+            stamm: self.stamm,
+            plural: self.plural,
+        }
+    }
+}
+
+impl<'a> ParsableDeklination<'a> for IDeklinationMaskulinumFemininum<'a> {
+    const GRUND: &'static str = "i-Deklination: gleichsilbig oder Stamm auf zwei Konsonanten";
+    const DEFAULT_GENUS: Option<Genus> = None;
+    const ALLOWS_FEMININUM: bool = true;
+    const ALLOWS_MASKULINUM: bool = true;
+    const ALLOWS_NEUTRUM: bool = false;
+
+    fn parse_wörterbuch_formen(
+        nominativ: &'a str,
+        genitiv: Option<&'a str>,
+    ) -> Result<Self, Ablehnungsgrund> {
+        let genitiv = genitiv.ok_or(Ablehnungsgrund::GenitivFehlt)?;
+
+        if let Some(stamm) = genitiv.strip_suffix("is") {
+            let rein = REINE_I_STÄMME.contains(&nominativ);
+            if !rein && !Self::ist_i_stamm(nominativ, genitiv, stamm) {
+                return Err(Ablehnungsgrund::StammPasstNicht);
+            }
+
+            Ok(Self {
+                nominativ_singular: Some(nominativ),
+                stamm,
+                plural: false,
+                rein,
+            })
+        } else if let Some(stamm) = genitiv.strip_suffix("ium") {
+            if !test_form(nominativ, stamm, "es") {
+                return Err(Ablehnungsgrund::NominativPasstNicht);
+            }
+
+            Ok(Self {
+                nominativ_singular: None,
+                stamm,
+                plural: true,
+                rein: false,
+            })
+        } else {
+            Err(Ablehnungsgrund::GenitivPasstNicht)
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::grammatik::{test_form, Genus, Kasus, Numerus};
+
+use super::{
+    Ablehnungsgrund, Deklination, Deklinationsklasse, Klassifikation, ParsableDeklination,
+};
+
+pub struct IDeklinationNeutrum<'a> {
+    nominativ_singular: Option<&'a str>,
+    stamm: &'a str,
+    plural: bool,
+}
+
+impl<'a> Deklination for IDeklinationNeutrum<'a> {
+    fn deklinieren(&self, numerus: Numerus, kasus: Kasus) -> Option<String> {
+        if self.plural && matches!(numerus, Numerus::Singular) {
+            return None;
+        }
+
+        let endung = match numerus {
+            Numerus::Singular => match kasus {
+                Kasus::Nominativ | Kasus::Vokativ | Kasus::Akkusativ => {
+                    return Some(String::from(self.nominativ_singular.unwrap()))
+                }
+                Kasus::Genitiv => "is",
+                Kasus::Dativ => "i",
+                Kasus::Ablativ => "i",
+                Kasus::Lokativ => "i",
+            },
+            Numerus::Plural => match kasus {
+                Kasus::Nominativ | Kasus::Vokativ => "ia",
+                Kasus::Genitiv => "ium",
+                Kasus::Dativ => "ibus",
+                Kasus::Akkusativ => "ia",
+                Kasus::Ablativ => "ibus",
+                Kasus::Lokativ => "ibus",
+            },
+        };
+
+        let mut result = String::with_capacity(self.stamm.len() + endung.len());
+        result.push_str(self.stamm);
+        result.push_str(endung);
+        Some(result)
+    }
+
+    fn klassifikation(&self) -> Klassifikation<'_> {
+        Klassifikation {
+            klasse: Deklinationsklasse::IDeklination,
+            stamm: self.stamm,
+            plural: self.plural,
+        }
+    }
+}
+
+impl<'a> ParsableDeklination<'a> for IDeklinationNeutrum<'a> {
+    const GRUND: &'static str = "i-Deklination (Neutrum): Nominativ auf -e, -al oder -ar";
+    const DEFAULT_GENUS: Option<Genus> = None;
+    const ALLOWS_MASKULINUM: bool = false;
+    const ALLOWS_FEMININUM: bool = false;
+    const ALLOWS_NEUTRUM: bool = true;
+
+    fn parse_wörterbuch_formen(
+        nominativ: &'a str,
+        genitiv: Option<&'a str>,
+    ) -> Result<Self, Ablehnungsgrund> {
+        let genitiv = genitiv.ok_or(Ablehnungsgrund::GenitivFehlt)?;
+
+        if let Some(stamm) = genitiv.strip_suffix("is") {
+            // mare, maris; animal, animalis; exemplar, exemplaris
+            if !test_form(nominativ, stamm, "e")
+                && !(nominativ == stamm && (stamm.ends_with("al") || stamm.ends_with("ar")))
+            {
+                return Err(Ablehnungsgrund::NominativPasstNicht);
+            }
+
+            Ok(Self {
+                nominativ_singular: Some(nominativ),
+                stamm,
+                plural: false,
+            })
+        } else if let Some(stamm) = genitiv.strip_suffix("ium") {
+            if !test_form(nominativ, stamm, "ia") {
+                return Err(Ablehnungsgrund::NominativPasstNicht);
+            }
+
+            Ok(Self {
+                nominativ_singular: None,
+                stamm,
+                plural: true,
+            })
+        } else {
+            Err(Ablehnungsgrund::GenitivPasstNicht)
+        }
+    }
+}
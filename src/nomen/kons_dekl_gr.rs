@@ -0,0 +1,82 @@
+use crate::grammatik::{test_form, Genus, Kasus, Numerus};
+
+use super::{
+    Ablehnungsgrund, Deklination, Deklinationsklasse, Klassifikation, ParsableDeklination,
+};
+
+// lampas, lampados; Pallas, Pallados; heros, heroos; gigas, gigantos
+pub struct GriechischeKonsonantischeDeklination<'a> {
+    nominativ_singular: &'a str,
+    stamm: &'a str,
+}
+
+impl<'a> Deklination for GriechischeKonsonantischeDeklination<'a> {
+    fn deklinieren(&self, numerus: Numerus, kasus: Kasus) -> Option<String> {
+        let endung = match numerus {
+            Numerus::Singular => match kasus {
+                Kasus::Nominativ | Kasus::Vokativ => {
+                    return Some(String::from(self.nominativ_singular))
+                }
+                Kasus::Genitiv => "os",
+                Kasus::Dativ => "i",
+                Kasus::Akkusativ => "a",
+                Kasus::Ablativ => "e",
+                Kasus::Lokativ => "e",
+            },
+            Numerus::Plural => match kasus {
+                Kasus::Nominativ | Kasus::Vokativ => "es",
+                Kasus::Genitiv => "um",
+                Kasus::Dativ => "ibus",
+                Kasus::Akkusativ => "as",
+                Kasus::Ablativ => "ibus",
+                Kasus::Lokativ => "ibus",
+            },
+        };
+
+        let mut result = String::with_capacity(self.stamm.len() + endung.len());
+        result.push_str(self.stamm);
+        result.push_str(endung);
+        Some(result)
+    }
+
+    fn klassifikation(&self) -> Klassifikation<'_> {
+        Klassifikation {
+            klasse: Deklinationsklasse::GriechischeKonsonantischeDeklination,
+            stamm: self.stamm,
+            plural: false,
+        }
+    }
+}
+
+impl<'a> ParsableDeklination<'a> for GriechischeKonsonantischeDeklination<'a> {
+    const GRUND: &'static str = "griechische konsonantische Deklination: Genitiv auf -os";
+    const DEFAULT_GENUS: Option<Genus> = None;
+    const ALLOWS_FEMININUM: bool = true;
+    const ALLOWS_MASKULINUM: bool = true;
+    const ALLOWS_NEUTRUM: bool = false;
+
+    fn parse_wörterbuch_formen(
+        nominativ: &'a str,
+        genitiv: Option<&'a str>,
+    ) -> Result<Self, Ablehnungsgrund> {
+        let genitiv = genitiv.ok_or(Ablehnungsgrund::GenitivFehlt)?;
+        let stamm = genitiv
+            .strip_suffix("os")
+            .ok_or(Ablehnungsgrund::GenitivPasstNicht)?;
+
+        // Der Dental des Stamms fällt vor dem -s des Nominativs aus: lampad-, lampas
+        let nominativ_stamm = stamm
+            .strip_suffix("nt")
+            .or_else(|| stamm.strip_suffix('d'))
+            .or_else(|| stamm.strip_suffix('t'))
+            .unwrap_or(stamm);
+        if !test_form(nominativ, nominativ_stamm, "s") {
+            return Err(Ablehnungsgrund::NominativPasstNicht);
+        }
+
+        Ok(Self {
+            nominativ_singular: nominativ,
+            stamm,
+        })
+    }
+}
@@ -1,6 +1,9 @@
 use crate::grammatik::{test_form, Genus, Kasus, Numerus};
 
-use super::{Deklination, ParsableDeklination};
+use super::{
+    i_dekl_mf::IDeklinationMaskulinumFemininum, Ablehnungsgrund, Deklination, Deklinationsklasse,
+    Klassifikation, ParsableDeklination,
+};
 
 pub struct KonsonantischeDeklinationMaskulinumFemininum<'a> {
     nominativ_singular: Option<&'a str>,
@@ -23,6 +26,7 @@ impl<'a> Deklination for KonsonantischeDeklinationMaskulinumFemininum<'a> {
                 Kasus::Dativ => "i",
                 Kasus::Akkusativ => "em",
                 Kasus::Ablativ => "e",
+                Kasus::Lokativ => "e",
             },
             Numerus::Plural => match kasus {
                 Kasus::Nominativ | Kasus::Vokativ => "es",
@@ -30,6 +34,7 @@ impl<'a> Deklination for KonsonantischeDeklinationMaskulinumFemininum<'a> {
                 Kasus::Dativ => "ibus",
                 Kasus::Akkusativ => "es",
                 Kasus::Ablativ => "ibus",
+                Kasus::Lokativ => "ibus",
             },
         };
 
@@ -38,38 +43,53 @@ impl<'a> Deklination for KonsonantischeDeklinationMaskulinumFemininum<'a> {
         result.push_str(endung);
         Some(result)
     }
+
+    fn klassifikation(&self) -> Klassifikation<'_> {
+        Klassifikation {
+            klasse: Deklinationsklasse::KonsonantischeDeklination,
+            stamm: self.stamm,
+            plural: self.plural,
+        }
+    }
 }
 
 impl<'a> ParsableDeklination<'a> for KonsonantischeDeklinationMaskulinumFemininum<'a> {
+    const GRUND: &'static str = "konsonantische Deklination: Genitiv auf -is";
     const DEFAULT_GENUS: Option<Genus> = None;
     const ALLOWS_FEMININUM: bool = true;
     const ALLOWS_MASKULINUM: bool = true;
     const ALLOWS_NEUTRUM: bool = false;
 
-    fn parse_wörterbuch_formen(nominativ: &'a str, genitiv: Option<&'a str>) -> Option<Self> {
-        let Some(genitiv) = genitiv else {
-            return None;
-        };
+    fn parse_wörterbuch_formen(
+        nominativ: &'a str,
+        genitiv: Option<&'a str>,
+    ) -> Result<Self, Ablehnungsgrund> {
+        let genitiv = genitiv.ok_or(Ablehnungsgrund::GenitivFehlt)?;
+
+        // i-Stämme werden von der i-Deklination übernommen
+        if IDeklinationMaskulinumFemininum::parse_wörterbuch_formen(nominativ, Some(genitiv))
+            .is_ok()
+        {
+            return Err(Ablehnungsgrund::StammPasstNicht);
+        }
 
-        if genitiv.ends_with("is") {
-            Some(Self {
+        if let Some(stamm) = genitiv.strip_suffix("is") {
+            Ok(Self {
                 nominativ_singular: Some(nominativ),
-                stamm: &genitiv[..genitiv.len() - 2],
+                stamm,
                 plural: false,
             })
-        } else if genitiv.ends_with("um") {
-            let stamm = &genitiv[..genitiv.len() - 2];
-            if test_form(nominativ, stamm, "es") {
-                Some(Self {
-                    nominativ_singular: None,
-                    stamm,
-                    plural: true,
-                })
-            } else {
-                None
+        } else if let Some(stamm) = genitiv.strip_suffix("um") {
+            if !test_form(nominativ, stamm, "es") {
+                return Err(Ablehnungsgrund::NominativPasstNicht);
             }
+            Ok(Self {
+                nominativ_singular: None,
+                stamm,
+                plural: true,
+            })
         } else {
-            None
+            Err(Ablehnungsgrund::GenitivPasstNicht)
         }
     }
 }
@@ -1,6 +1,9 @@
 use crate::grammatik::{test_form, Genus, Kasus, Numerus};
 
-use super::{Deklination, ParsableDeklination};
+use super::{
+    i_dekl_n::IDeklinationNeutrum, Ablehnungsgrund, Deklination, Deklinationsklasse,
+    Klassifikation, ParsableDeklination,
+};
 
 pub struct KonsonantischeDeklinationNeutrum<'a> {
     nominativ_singular: Option<&'a str>,
@@ -22,6 +25,7 @@ impl<'a> Deklination for KonsonantischeDeklinationNeutrum<'a> {
                 Kasus::Genitiv => "is",
                 Kasus::Dativ => "i",
                 Kasus::Ablativ => "e",
+                Kasus::Lokativ => "e",
             },
             Numerus::Plural => match kasus {
                 Kasus::Nominativ | Kasus::Vokativ => "a",
@@ -29,6 +33,7 @@ impl<'a> Deklination for KonsonantischeDeklinationNeutrum<'a> {
                 Kasus::Dativ => "ibus",
                 Kasus::Akkusativ => "a",
                 Kasus::Ablativ => "ibus",
+                Kasus::Lokativ => "ibus",
             },
         };
 
@@ -37,38 +42,55 @@ impl<'a> Deklination for KonsonantischeDeklinationNeutrum<'a> {
         result.push_str(endung);
         Some(result)
     }
+
+    fn klassifikation(&self) -> Klassifikation<'_> {
+        Klassifikation {
+            klasse: Deklinationsklasse::KonsonantischeDeklination,
+            stamm: self.stamm,
+            plural: self.plural,
+        }
+    }
 }
 
 impl<'a> ParsableDeklination<'a> for KonsonantischeDeklinationNeutrum<'a> {
+    const GRUND: &'static str = "konsonantische Deklination (Neutrum): Genitiv auf -is";
     const DEFAULT_GENUS: Option<Genus> = None;
     const ALLOWS_MASKULINUM: bool = false;
     const ALLOWS_FEMININUM: bool = false;
     const ALLOWS_NEUTRUM: bool = true;
 
-    fn parse_wörterbuch_formen(nominativ: &'a str, genitiv: Option<&'a str>) -> Option<Self> {
-        let Some(genitiv) = genitiv else {
-            return None;
-        };
+    fn parse_wörterbuch_formen(
+        nominativ: &'a str,
+        genitiv: Option<&'a str>,
+    ) -> Result<Self, Ablehnungsgrund> {
+        let genitiv = genitiv.ok_or(Ablehnungsgrund::GenitivFehlt)?;
 
-        if genitiv.ends_with("is") {
-            Some(Self {
+        // i-Stämme werden von der i-Deklination übernommen
+        if IDeklinationNeutrum::parse_wörterbuch_formen(nominativ, Some(genitiv)).is_ok() {
+            return Err(Ablehnungsgrund::StammPasstNicht);
+        }
+
+        if let Some(stamm) = genitiv.strip_suffix("is") {
+            Ok(Self {
                 nominativ_singular: Some(nominativ),
-                stamm: &genitiv[..genitiv.len() - 2],
+                stamm,
                 plural: false,
             })
-        } else if genitiv.ends_with("um") {
-            let stamm = &genitiv[..genitiv.len() - 2];
-            if test_form(nominativ, stamm, "a") {
-                Some(Self {
-                    nominativ_singular: None,
-                    stamm,
-                    plural: true,
-                })
-            } else {
-                None
+        } else if let Some(stamm) = genitiv.strip_suffix("um") {
+            if !test_form(nominativ, stamm, "a") {
+                return Err(Ablehnungsgrund::NominativPasstNicht);
             }
+            // cornua, cornuum gehört zur u-Deklination
+            if stamm.ends_with('u') {
+                return Err(Ablehnungsgrund::StammPasstNicht);
+            }
+            Ok(Self {
+                nominativ_singular: None,
+                stamm,
+                plural: true,
+            })
         } else {
-            None
+            Err(Ablehnungsgrund::GenitivPasstNicht)
         }
     }
 }
@@ -1,57 +1,123 @@
-use crate::grammatik::{test_form, Genus, Kasus, Numerus};
+use std::fmt;
+
+use crate::grammatik::{self, test_form, Genus, Kasus, Numerus};
 
 use self::{
-    a_dekl::ADeklination, e_dekl::EDeklination,
+    a_dekl::ADeklination, a_dekl_gr::GriechischeADeklination, e_dekl::EDeklination,
+    i_dekl_mf::IDeklinationMaskulinumFemininum, i_dekl_n::IDeklinationNeutrum,
+    kons_dekl_gr::GriechischeKonsonantischeDeklination,
     kons_dekl_mf::KonsonantischeDeklinationMaskulinumFemininum,
-    kons_dekl_n::KonsonantischeDeklinationNeutrum, o_dekl_mf::ODeklinationMaskulinumFemininum,
-    o_dekl_n::ODeklinationNeutrum, u_dekl::UDeklination,
+    kons_dekl_n::KonsonantischeDeklinationNeutrum, o_dekl_er::ODeklinationMaskulinumEr,
+    o_dekl_gr_mf::GriechischeODeklinationMaskulinumFemininum,
+    o_dekl_gr_n::GriechischeODeklinationNeutrum, o_dekl_mf::ODeklinationMaskulinumFemininum,
+    o_dekl_n::ODeklinationNeutrum, u_dekl::UDeklination, u_dekl_n::UDeklinationNeutrum,
+    unregelmaessig::UnregelmäßigesNomen,
 };
 
 mod a_dekl;
+mod a_dekl_gr;
 mod e_dekl;
+mod i_dekl_mf;
+mod i_dekl_n;
+mod kons_dekl_gr;
 mod kons_dekl_mf;
 mod kons_dekl_n;
+mod o_dekl_er;
+mod o_dekl_gr_mf;
+mod o_dekl_gr_n;
 mod o_dekl_mf;
 mod o_dekl_n;
 mod u_dekl;
+mod u_dekl_n;
+mod unregelmaessig;
 
 #[cfg(test)]
 mod tests;
 
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub enum Deklinationsklasse {
+    ADeklination,
+    ODeklination,
+    UDeklination,
+    EDeklination,
+    KonsonantischeDeklination,
+    IDeklination,
+    GriechischeADeklination,
+    GriechischeODeklination,
+    GriechischeKonsonantischeDeklination,
+    Unregelmäßig,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Klassifikation<'a> {
+    klasse: Deklinationsklasse,
+    stamm: &'a str,
+    plural: bool,
+}
+
+impl<'a> Klassifikation<'a> {
+    pub fn get_klasse(&self) -> Deklinationsklasse {
+        self.klasse
+    }
+
+    pub fn get_stamm(&self) -> &'a str {
+        self.stamm
+    }
+
+    pub fn is_plural(&self) -> bool {
+        self.plural
+    }
+}
+
 trait Deklination {
     fn deklinieren(&self, numerus: Numerus, kasus: Kasus) -> Option<String>;
+
+    // Alle zulässigen Formen, die bevorzugte Form zuerst
+    fn varianten(&self, numerus: Numerus, kasus: Kasus) -> Vec<String> {
+        self.deklinieren(numerus, kasus).into_iter().collect()
+    }
+
+    fn klassifikation(&self) -> Klassifikation<'_>;
 }
 
 trait ParsableDeklination<'a>: Deklination + Sized {
+    const GRUND: &'static str;
     const DEFAULT_GENUS: Option<Genus>;
     const ALLOWS_FEMININUM: bool;
     const ALLOWS_MASKULINUM: bool;
     const ALLOWS_NEUTRUM: bool;
 
-    fn parse_wörterbuch_formen(nominativ: &'a str, genitiv: Option<&'a str>) -> Option<Self>;
+    fn parse_wörterbuch_formen(
+        nominativ: &'a str,
+        genitiv: Option<&'a str>,
+    ) -> Result<Self, Ablehnungsgrund>;
+
+    fn parse_wörterbuch_eintrag(
+        eintrag: &WörterbuchEintrag<'a>,
+    ) -> Result<(Genus, Self), Ablehnungsgrund> {
+        let deklination = Self::parse_wörterbuch_formen(eintrag.nominativ, eintrag.genitiv)?;
 
-    fn parse_wörterbuch_eintrag(eintrag: &WörterbuchEintrag<'a>) -> Option<(Genus, Self)> {
         let genus = match eintrag.genus {
-            None => match Self::DEFAULT_GENUS {
-                Some(default) => default,
-                None => return None,
-            },
-            Some(Genus::Maskulinum) if !Self::ALLOWS_MASKULINUM => return None,
-            Some(Genus::Femininum) if !Self::ALLOWS_FEMININUM => return None,
-            Some(Genus::Neutrum) if !Self::ALLOWS_NEUTRUM => return None,
+            None => Self::DEFAULT_GENUS.ok_or(Ablehnungsgrund::GenusFehlt)?,
+            Some(genus @ Genus::Maskulinum) if !Self::ALLOWS_MASKULINUM => {
+                return Err(Ablehnungsgrund::GenusNichtErlaubt(genus))
+            }
+            Some(genus @ Genus::Femininum) if !Self::ALLOWS_FEMININUM => {
+                return Err(Ablehnungsgrund::GenusNichtErlaubt(genus))
+            }
+            Some(genus @ Genus::Neutrum) if !Self::ALLOWS_NEUTRUM => {
+                return Err(Ablehnungsgrund::GenusNichtErlaubt(genus))
+            }
             Some(genus) => genus,
         };
 
-        let deklination = match Self::parse_wörterbuch_formen(eintrag.nominativ, eintrag.genitiv) {
-            Some(deklination) => deklination,
-            None => return None,
-        };
-
-        Some((genus, deklination))
+        Ok((genus, deklination))
     }
 }
 
 trait StammDeklination<'a>: Sized {
+    const KLASSE: Deklinationsklasse;
+    const GRUND: &'static str;
     const DEFAULT_GENUS: Option<Genus>;
     const ALLOWS_FEMININUM: bool;
     const ALLOWS_MASKULINUM: bool;
@@ -59,6 +125,7 @@ trait StammDeklination<'a>: Sized {
 
     const REQUIRE_GENITIVE_SINGULAR: bool = false;
     const REQUIRE_GENITIVE_PLURAL: bool = true;
+    const ALLOWS_PLURAL_ONLY: bool = true;
 
     fn new(stamm: &'a str, plural: bool) -> Self;
 
@@ -71,6 +138,11 @@ trait StammDeklination<'a>: Sized {
     fn get_endung_instance(&self, _: Numerus, _: Kasus) -> Option<&'static str> {
         None
     }
+
+    // Weitere zulässige Endungen neben der bevorzugten
+    fn get_varianten(&self, _: Numerus, _: Kasus) -> &'static [&'static str] {
+        &[]
+    }
 }
 
 impl<'a, T> Deklination for T
@@ -85,10 +157,7 @@ where
         let stamm = self.get_stamm();
         let endung = match Self::get_endung(numerus, kasus) {
             Some(endung) => endung,
-            None => match self.get_endung_instance(numerus, kasus) {
-                Some(endung) => endung,
-                None => return None,
-            },
+            None => self.get_endung_instance(numerus, kasus)?,
         };
 
         let mut result = String::with_capacity(stamm.len() + endung.len());
@@ -96,19 +165,50 @@ where
         result.push_str(endung);
         Some(result)
     }
+
+    fn varianten(&self, numerus: Numerus, kasus: Kasus) -> Vec<String> {
+        let Some(form) = self.deklinieren(numerus, kasus) else {
+            return Vec::new();
+        };
+
+        let stamm = self.get_stamm();
+        let mut varianten = vec![form];
+        for endung in self.get_varianten(numerus, kasus) {
+            varianten.push([stamm, endung].concat());
+        }
+        varianten
+    }
+
+    fn klassifikation(&self) -> Klassifikation<'_> {
+        Klassifikation {
+            klasse: T::KLASSE,
+            stamm: self.get_stamm(),
+            plural: self.is_plural(),
+        }
+    }
 }
 
 impl<'a, T> ParsableDeklination<'a> for T
 where
     T: StammDeklination<'a>,
 {
+    const GRUND: &'static str = Self::GRUND;
     const DEFAULT_GENUS: Option<Genus> = Self::DEFAULT_GENUS;
     const ALLOWS_FEMININUM: bool = Self::ALLOWS_FEMININUM;
     const ALLOWS_MASKULINUM: bool = Self::ALLOWS_MASKULINUM;
     const ALLOWS_NEUTRUM: bool = Self::ALLOWS_NEUTRUM;
 
-    fn parse_wörterbuch_formen(nominativ: &'a str, genitiv: Option<&'a str>) -> Option<Self> {
+    fn parse_wörterbuch_formen(
+        nominativ: &'a str,
+        genitiv: Option<&'a str>,
+    ) -> Result<Self, Ablehnungsgrund> {
+        let mut fehler = Ablehnungsgrund::NominativPasstNicht;
+
         for numerus in Numerus::ALLE {
+            if matches!(numerus, Numerus::Plural) && !T::ALLOWS_PLURAL_ONLY {
+                continue;
+            }
+
             let nominativ_endung = match T::get_endung(numerus, Kasus::Nominativ) {
                 Some(endung) => endung,
                 None => continue,
@@ -118,94 +218,255 @@ where
                 None => continue,
             };
 
-            let stamm = if nominativ.ends_with(nominativ_endung) {
-                &nominativ[..nominativ.len() - nominativ_endung.len()]
-            } else {
+            let Some(stamm) = nominativ.strip_suffix(nominativ_endung) else {
                 continue;
             };
 
             if let Some(genitiv) = genitiv {
                 if !test_form(genitiv, stamm, genitiv_endung) {
+                    fehler = Ablehnungsgrund::GenitivPasstNicht;
                     continue;
                 }
             } else if match numerus {
                 Numerus::Singular => T::REQUIRE_GENITIVE_SINGULAR,
                 Numerus::Plural => T::REQUIRE_GENITIVE_PLURAL,
             } {
+                fehler = Ablehnungsgrund::GenitivFehlt;
                 continue;
             }
 
-            return Some(T::new(stamm, matches!(numerus, Numerus::Plural)));
+            return Ok(T::new(stamm, matches!(numerus, Numerus::Plural)));
         }
 
-        None
+        Err(fehler)
     }
 }
 
+// Nomen, die auch ohne Ortsname zu sein einen Lokativ bilden
+const LOKATIV_NOMEN: [&str; 3] = ["domus", "rus", "humus"];
+
 #[derive(Clone, Copy)]
 pub struct WörterbuchEintrag<'a> {
     pub nominativ: &'a str,
     pub genitiv: Option<&'a str>,
     pub genus: Option<Genus>,
+    // Städte und kleine Inseln, zB Roma, Carthago oder Delos
+    pub ortsname: bool,
 }
 
 impl<'a> WörterbuchEintrag<'a> {
-    fn parse_deklination<T>(&self) -> Option<(Genus, Box<dyn Deklination + 'a>)>
-    where
+    fn parse_deklination<T>(
+        &self,
+        kandidaten: &mut Vec<Kandidat<'a>>,
+        ablehnungen: &mut Vec<Ablehnung>,
+    ) where
         T: ParsableDeklination<'a> + 'a,
     {
         match T::parse_wörterbuch_eintrag(self) {
-            Some((genus, deklination)) => Some((genus, Box::new(deklination))),
-            None => None,
+            Ok((genus, deklination)) => {
+                kandidaten.push(Kandidat::new(self, T::GRUND, genus, Box::new(deklination)))
+            }
+            Err(grund) => ablehnungen.push(Ablehnung::new(T::GRUND, grund)),
         }
     }
 
-    fn parse(&self) -> Option<(Genus, Box<dyn Deklination + 'a>)> {
-        if let result @ Some(_) =
-            self.parse_deklination::<KonsonantischeDeklinationMaskulinumFemininum>()
-        {
-            result
-        } else if let result @ Some(_) =
-            self.parse_deklination::<KonsonantischeDeklinationNeutrum>()
-        {
-            result
-        } else if let result @ Some(_) = self.parse_deklination::<ODeklinationMaskulinumFemininum>()
-        {
-            result
-        } else if let result @ Some(_) = self.parse_deklination::<ODeklinationNeutrum>() {
-            result
-        } else if let result @ Some(_) = self.parse_deklination::<ADeklination>() {
-            result
-        } else if let result @ Some(_) = self.parse_deklination::<EDeklination>() {
-            result
-        } else if let result @ Some(_) = self.parse_deklination::<UDeklination>() {
-            result
-        } else {
-            None
+    fn parse(&self) -> (Vec<Kandidat<'a>>, Vec<Ablehnung>) {
+        match UnregelmäßigesNomen::parse(self) {
+            Some(Ok((genus, nomen))) => {
+                let kandidat =
+                    Kandidat::new(self, UnregelmäßigesNomen::GRUND, genus, Box::new(nomen));
+                return (vec![kandidat], Vec::new());
+            }
+            Some(Err(grund)) => {
+                let ablehnung = Ablehnung::new(UnregelmäßigesNomen::GRUND, grund);
+                return (Vec::new(), vec![ablehnung]);
+            }
+            None => {}
         }
+
+        let mut kandidaten = Vec::new();
+        let mut ablehnungen = Vec::new();
+        self.parse_deklination::<IDeklinationMaskulinumFemininum>(
+            &mut kandidaten,
+            &mut ablehnungen,
+        );
+        self.parse_deklination::<IDeklinationNeutrum>(&mut kandidaten, &mut ablehnungen);
+        self.parse_deklination::<UDeklinationNeutrum>(&mut kandidaten, &mut ablehnungen);
+        self.parse_deklination::<KonsonantischeDeklinationMaskulinumFemininum>(
+            &mut kandidaten,
+            &mut ablehnungen,
+        );
+        self.parse_deklination::<KonsonantischeDeklinationNeutrum>(
+            &mut kandidaten,
+            &mut ablehnungen,
+        );
+        self.parse_deklination::<ODeklinationMaskulinumFemininum>(
+            &mut kandidaten,
+            &mut ablehnungen,
+        );
+        self.parse_deklination::<ODeklinationMaskulinumEr>(&mut kandidaten, &mut ablehnungen);
+        self.parse_deklination::<ODeklinationNeutrum>(&mut kandidaten, &mut ablehnungen);
+        self.parse_deklination::<GriechischeODeklinationMaskulinumFemininum>(
+            &mut kandidaten,
+            &mut ablehnungen,
+        );
+        self.parse_deklination::<GriechischeODeklinationNeutrum>(&mut kandidaten, &mut ablehnungen);
+        self.parse_deklination::<GriechischeADeklination>(&mut kandidaten, &mut ablehnungen);
+        self.parse_deklination::<GriechischeKonsonantischeDeklination>(
+            &mut kandidaten,
+            &mut ablehnungen,
+        );
+        self.parse_deklination::<ADeklination>(&mut kandidaten, &mut ablehnungen);
+        self.parse_deklination::<EDeklination>(&mut kandidaten, &mut ablehnungen);
+        self.parse_deklination::<UDeklination>(&mut kandidaten, &mut ablehnungen);
+        (kandidaten, ablehnungen)
     }
 }
 
+// Eine Deklination, die zu einem Wörterbucheintrag passt
+pub struct Kandidat<'a> {
+    grund: &'static str,
+    nomen: Nomen<'a>,
+}
+
+impl<'a> Kandidat<'a> {
+    fn new(
+        eintrag: &WörterbuchEintrag<'a>,
+        grund: &'static str,
+        genus: Genus,
+        deklination: Box<dyn Deklination + 'a>,
+    ) -> Self {
+        Self {
+            grund,
+            nomen: Nomen {
+                genus,
+                lokativ: eintrag.ortsname || LOKATIV_NOMEN.contains(&eintrag.nominativ),
+                deklination,
+            },
+        }
+    }
+
+    pub fn get_grund(&self) -> &'static str {
+        self.grund
+    }
+
+    pub fn get_genus(&self) -> Genus {
+        self.nomen.genus
+    }
+
+    pub fn get_nomen(&self) -> &Nomen<'a> {
+        &self.nomen
+    }
+
+    pub fn into_nomen(self) -> Nomen<'a> {
+        self.nomen
+    }
+}
+
+impl<'a> fmt::Debug for Kandidat<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Kandidat")
+            .field("grund", &self.grund)
+            .field("genus", &self.nomen.genus)
+            .finish()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Ablehnungsgrund {
+    NominativPasstNicht,
+    GenitivPasstNicht,
+    GenitivFehlt,
+    StammPasstNicht,
+    GenusFehlt,
+    GenusNichtErlaubt(Genus),
+}
+
+impl fmt::Display for Ablehnungsgrund {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::NominativPasstNicht => write!(f, "Endung des Nominativs passt nicht"),
+            Self::GenitivPasstNicht => write!(f, "Endung des Genitivs passt nicht"),
+            Self::GenitivFehlt => write!(f, "Genitiv wird benötigt, fehlt aber"),
+            Self::StammPasstNicht => write!(f, "Stamm passt nicht"),
+            Self::GenusFehlt => write!(f, "Geschlecht wird benötigt, fehlt aber"),
+            Self::GenusNichtErlaubt(genus) => write!(f, "Geschlecht {genus:?} nicht möglich"),
+        }
+    }
+}
+
+pub type Ablehnung = grammatik::Ablehnung<Ablehnungsgrund>;
+
+#[derive(Debug)]
+pub enum ParseFehler<'a> {
+    KeineDeklination(Vec<Ablehnung>),
+    Mehrdeutig(Vec<Kandidat<'a>>),
+}
+
+impl<'a> fmt::Display for ParseFehler<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::KeineDeklination(ref ablehnungen) => {
+                write!(f, "keine passende Deklination: ")?;
+                Ablehnung::fmt_liste(ablehnungen, f)
+            }
+            Self::Mehrdeutig(ref kandidaten) => {
+                write!(f, "mehrdeutiger Eintrag: ")?;
+                for (i, kandidat) in kandidaten.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{} ({:?})", kandidat.grund, kandidat.get_genus())?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<'a> std::error::Error for ParseFehler<'a> {}
+
 pub struct Nomen<'a> {
     genus: Genus,
+    lokativ: bool,
     deklination: Box<dyn Deklination + 'a>,
 }
 
 impl<'a> Nomen<'a> {
-    pub fn parse(eintrag: &WörterbuchEintrag<'a>) -> Option<Self> {
-        let (genus, deklination) = match eintrag.parse() {
-            Some(result) => result,
-            None => return None,
-        };
+    pub fn parse(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, ParseFehler<'a>> {
+        let (mut kandidaten, ablehnungen) = eintrag.parse();
+        match kandidaten.len() {
+            0 => Err(ParseFehler::KeineDeklination(ablehnungen)),
+            1 => Ok(kandidaten.remove(0).into_nomen()),
+            _ => Err(ParseFehler::Mehrdeutig(kandidaten)),
+        }
+    }
 
-        Some(Self { genus, deklination })
+    pub fn kandidaten(eintrag: &WörterbuchEintrag<'a>) -> Vec<Kandidat<'a>> {
+        eintrag.parse().0
     }
 
     pub fn get_genus(&self) -> Genus {
         self.genus
     }
 
+    pub fn get_klassifikation(&self) -> Klassifikation<'_> {
+        self.deklination.klassifikation()
+    }
+
     pub fn deklinieren(&self, numerus: Numerus, kasus: Kasus) -> Option<String> {
+        if matches!(kasus, Kasus::Lokativ) && !self.lokativ {
+            return None;
+        }
+
         self.deklination.deklinieren(numerus, kasus)
     }
+
+    pub fn varianten(&self, numerus: Numerus, kasus: Kasus) -> Vec<String> {
+        if matches!(kasus, Kasus::Lokativ) && !self.lokativ {
+            return Vec::new();
+        }
+
+        self.deklination.varianten(numerus, kasus)
+    }
 }
@@ -0,0 +1,72 @@
+use crate::grammatik::{Genus, Kasus, Numerus};
+
+use super::{
+    o_dekl_mf::ODeklinationMaskulinumFemininum, Ablehnungsgrund, Deklination, Deklinationsklasse,
+    Klassifikation, ParsableDeklination, StammDeklination,
+};
+
+// puer, pueri; ager, agri; vir, viri
+pub struct ODeklinationMaskulinumEr<'a> {
+    nominativ_singular: &'a str,
+    stamm: &'a str,
+}
+
+impl<'a> Deklination for ODeklinationMaskulinumEr<'a> {
+    fn deklinieren(&self, numerus: Numerus, kasus: Kasus) -> Option<String> {
+        if let (Numerus::Singular, Kasus::Nominativ | Kasus::Vokativ) = (numerus, kasus) {
+            return Some(String::from(self.nominativ_singular));
+        }
+
+        let endung = ODeklinationMaskulinumFemininum::get_endung(numerus, kasus)?;
+
+        let mut result = String::with_capacity(self.stamm.len() + endung.len());
+        result.push_str(self.stamm);
+        result.push_str(endung);
+        Some(result)
+    }
+
+    fn klassifikation(&self) -> Klassifikation<'_> {
+        Klassifikation {
+            klasse: Deklinationsklasse::ODeklination,
+            stamm: self.stamm,
+            plural: false,
+        }
+    }
+}
+
+impl<'a> ParsableDeklination<'a> for ODeklinationMaskulinumEr<'a> {
+    const GRUND: &'static str = "o-Deklination: Nominativ auf -er oder -ir";
+    const DEFAULT_GENUS: Option<Genus> = Some(Genus::Maskulinum);
+    const ALLOWS_MASKULINUM: bool = true;
+    const ALLOWS_FEMININUM: bool = false;
+    const ALLOWS_NEUTRUM: bool = false;
+
+    fn parse_wörterbuch_formen(
+        nominativ: &'a str,
+        genitiv: Option<&'a str>,
+    ) -> Result<Self, Ablehnungsgrund> {
+        if !nominativ.ends_with("er") && !nominativ.ends_with("ir") {
+            return Err(Ablehnungsgrund::NominativPasstNicht);
+        }
+
+        let stamm = genitiv
+            .ok_or(Ablehnungsgrund::GenitivFehlt)?
+            .strip_suffix('i')
+            .ok_or(Ablehnungsgrund::GenitivPasstNicht)?;
+
+        // Das e des Nominativs bleibt erhalten (puer, pueri) oder fällt aus (ager, agri)
+        let ohne_e = nominativ.len() - 2;
+        if stamm != nominativ
+            && !(nominativ.ends_with("er")
+                && stamm.starts_with(&nominativ[..ohne_e])
+                && stamm[ohne_e..] == nominativ[ohne_e + 1..])
+        {
+            return Err(Ablehnungsgrund::StammPasstNicht);
+        }
+
+        Ok(Self {
+            nominativ_singular: nominativ,
+            stamm,
+        })
+    }
+}
@@ -0,0 +1,55 @@
+use crate::grammatik::{Genus, Kasus, Numerus};
+
+use super::{Deklinationsklasse, StammDeklination};
+
+// Delos, Deli
+pub struct GriechischeODeklinationMaskulinumFemininum<'a> {
+    stamm: &'a str,
+    plural: bool,
+}
+
+impl<'a> StammDeklination<'a> for GriechischeODeklinationMaskulinumFemininum<'a> {
+    const KLASSE: Deklinationsklasse = Deklinationsklasse::GriechischeODeklination;
+    const GRUND: &'static str = "griechische o-Deklination: Nominativ auf -os";
+    const DEFAULT_GENUS: Option<Genus> = Some(Genus::Maskulinum);
+    const ALLOWS_MASKULINUM: bool = true;
+    const ALLOWS_FEMININUM: bool = true;
+    const ALLOWS_NEUTRUM: bool = false;
+
+    // Im Plural nicht von der lateinischen o-Deklination zu unterscheiden
+    const ALLOWS_PLURAL_ONLY: bool = false;
+
+    fn new(stamm: &'a str, plural: bool) -> Self {
+        Self { stamm, plural }
+    }
+
+    fn get_stamm(&self) -> &'a str {
+        self.stamm
+    }
+
+    fn is_plural(&self) -> bool {
+        self.plural
+    }
+
+    fn get_endung(numerus: Numerus, kasus: Kasus) -> Option<&'static str> {
+        Some(match numerus {
+            Numerus::Singular => match kasus {
+                Kasus::Nominativ => "os",
+                Kasus::Genitiv => "i",
+                Kasus::Dativ => "o",
+                Kasus::Akkusativ => "on",
+                Kasus::Ablativ => "o",
+                Kasus::Lokativ => "i",
+                Kasus::Vokativ => "e",
+            },
+            Numerus::Plural => match kasus {
+                Kasus::Nominativ | Kasus::Vokativ => "i",
+                Kasus::Genitiv => "orum",
+                Kasus::Dativ => "is",
+                Kasus::Akkusativ => "os",
+                Kasus::Ablativ => "is",
+                Kasus::Lokativ => "is",
+            },
+        })
+    }
+}
@@ -0,0 +1,54 @@
+use crate::grammatik::{Genus, Kasus, Numerus};
+
+use super::{Deklinationsklasse, StammDeklination};
+
+// barbiton, barbiti
+pub struct GriechischeODeklinationNeutrum<'a> {
+    stamm: &'a str,
+    plural: bool,
+}
+
+impl<'a> StammDeklination<'a> for GriechischeODeklinationNeutrum<'a> {
+    const KLASSE: Deklinationsklasse = Deklinationsklasse::GriechischeODeklination;
+    const GRUND: &'static str = "griechische o-Deklination (Neutrum): Nominativ auf -on";
+    const DEFAULT_GENUS: Option<Genus> = Some(Genus::Neutrum);
+    const ALLOWS_MASKULINUM: bool = false;
+    const ALLOWS_FEMININUM: bool = false;
+    const ALLOWS_NEUTRUM: bool = true;
+
+    // Im Plural nicht von der lateinischen o-Deklination zu unterscheiden
+    const ALLOWS_PLURAL_ONLY: bool = false;
+
+    fn new(stamm: &'a str, plural: bool) -> Self {
+        Self { stamm, plural }
+    }
+
+    fn get_stamm(&self) -> &'a str {
+        self.stamm
+    }
+
+    fn is_plural(&self) -> bool {
+        self.plural
+    }
+
+    fn get_endung(numerus: Numerus, kasus: Kasus) -> Option<&'static str> {
+        Some(match numerus {
+            Numerus::Singular => match kasus {
+                Kasus::Nominativ | Kasus::Vokativ => "on",
+                Kasus::Genitiv => "i",
+                Kasus::Dativ => "o",
+                Kasus::Akkusativ => "on",
+                Kasus::Ablativ => "o",
+                Kasus::Lokativ => "i",
+            },
+            Numerus::Plural => match kasus {
+                Kasus::Nominativ | Kasus::Vokativ => "a",
+                Kasus::Genitiv => "orum",
+                Kasus::Dativ => "is",
+                Kasus::Akkusativ => "a",
+                Kasus::Ablativ => "is",
+                Kasus::Lokativ => "is",
+            },
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use crate::grammatik::{Genus, Kasus, Numerus};
 
-use super::StammDeklination;
+use super::{Deklinationsklasse, StammDeklination};
 
 pub struct ODeklinationMaskulinumFemininum<'a> {
     stamm: &'a str,
@@ -8,6 +8,8 @@ pub struct ODeklinationMaskulinumFemininum<'a> {
 }
 
 impl<'a> StammDeklination<'a> for ODeklinationMaskulinumFemininum<'a> {
+    const KLASSE: Deklinationsklasse = Deklinationsklasse::ODeklination;
+    const GRUND: &'static str = "o-Deklination: Nominativ auf -us";
     const DEFAULT_GENUS: Option<Genus> = Some(Genus::Maskulinum);
     const ALLOWS_MASKULINUM: bool = true;
     const ALLOWS_FEMININUM: bool = true;
@@ -33,6 +35,7 @@ impl<'a> StammDeklination<'a> for ODeklinationMaskulinumFemininum<'a> {
                 Kasus::Dativ => "o",
                 Kasus::Akkusativ => "um",
                 Kasus::Ablativ => "o",
+                Kasus::Lokativ => "i",
                 Kasus::Vokativ => return None,
             },
             Numerus::Plural => match kasus {
@@ -41,6 +44,7 @@ impl<'a> StammDeklination<'a> for ODeklinationMaskulinumFemininum<'a> {
                 Kasus::Dativ => "is",
                 Kasus::Akkusativ => "os",
                 Kasus::Ablativ => "is",
+                Kasus::Lokativ => "is",
             },
         })
     }
@@ -52,4 +56,12 @@ impl<'a> StammDeklination<'a> for ODeklinationMaskulinumFemininum<'a> {
             None
         }
     }
+
+    // filius, fili; ingenium, ingeni
+    fn get_varianten(&self, numerus: Numerus, kasus: Kasus) -> &'static [&'static str] {
+        match (numerus, kasus) {
+            (Numerus::Singular, Kasus::Genitiv) if self.stamm.ends_with('i') => &[""],
+            _ => &[],
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use crate::grammatik::{Genus, Kasus, Numerus};
 
-use super::StammDeklination;
+use super::{Deklinationsklasse, StammDeklination};
 
 pub struct ODeklinationNeutrum<'a> {
     stamm: &'a str,
@@ -8,6 +8,8 @@ pub struct ODeklinationNeutrum<'a> {
 }
 
 impl<'a> StammDeklination<'a> for ODeklinationNeutrum<'a> {
+    const KLASSE: Deklinationsklasse = Deklinationsklasse::ODeklination;
+    const GRUND: &'static str = "o-Deklination (Neutrum): Nominativ auf -um";
     const DEFAULT_GENUS: Option<Genus> = Some(Genus::Neutrum);
     const ALLOWS_MASKULINUM: bool = false;
     const ALLOWS_FEMININUM: bool = false;
@@ -33,6 +35,7 @@ impl<'a> StammDeklination<'a> for ODeklinationNeutrum<'a> {
                 Kasus::Dativ => "o",
                 Kasus::Akkusativ => "um",
                 Kasus::Ablativ => "o",
+                Kasus::Lokativ => "i",
             },
             Numerus::Plural => match kasus {
                 Kasus::Nominativ | Kasus::Vokativ => "a",
@@ -40,7 +43,16 @@ impl<'a> StammDeklination<'a> for ODeklinationNeutrum<'a> {
                 Kasus::Dativ => "is",
                 Kasus::Akkusativ => "a",
                 Kasus::Ablativ => "is",
+                Kasus::Lokativ => "is",
             },
         })
     }
+
+    // filius, fili; ingenium, ingeni
+    fn get_varianten(&self, numerus: Numerus, kasus: Kasus) -> &'static [&'static str] {
+        match (numerus, kasus) {
+            (Numerus::Singular, Kasus::Genitiv) if self.stamm.ends_with('i') => &[""],
+            _ => &[],
+        }
+    }
 }
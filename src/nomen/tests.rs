@@ -1,9 +1,9 @@
-use super::{Nomen, WörterbuchEintrag};
+use super::{Ablehnungsgrund, Deklinationsklasse, Nomen, ParseFehler, WörterbuchEintrag};
 use crate::grammatik::{
-    Genus::{Maskulinum as M, Neutrum as N},
+    Genus::{Femininum as F, Maskulinum as M, Neutrum as N},
     Kasus::{
-        Ablativ as Abl, Akkusativ as Akk, Dativ as Dat, Genitiv as Gen, Nominativ as Nom,
-        Vokativ as Vok,
+        Ablativ as Abl, Akkusativ as Akk, Dativ as Dat, Genitiv as Gen, Lokativ as Lok,
+        Nominativ as Nom, Vokativ as Vok,
     },
     Numerus::{Plural as Pl, Singular as Sg},
     *,
@@ -20,10 +20,11 @@ macro_rules! test_deklination {
             test_deklination!(@PRIVATE; $nominativ, None::<&str>, None::<Genus>, $($kasus $numerus => $form,)*);
         };
         (@PRIVATE; $nominativ:literal, $genitiv:expr, $genus:expr, $($kasus:ident $numerus:ident => $form:literal,)*) => {
-            #[allow(unused)] let Some(nomen) = Nomen::parse(&WörterbuchEintrag {
+            #[allow(unused)] let Ok(nomen) = Nomen::parse(&WörterbuchEintrag {
                 nominativ: $nominativ,
                 genitiv: $genitiv,
-                genus: $genus
+                genus: $genus,
+                ortsname: false,
             }) else {
                 panic!("failed to create nomen: {}, {:?}, {:?}", $nominativ, $genitiv, $genus);
             };
@@ -37,7 +38,7 @@ macro_rules! test_deklination {
 #[test]
 fn test_o_dekl_mf() {
     test_deklination! {
-        "servus",
+        "servus", "servi",
 
         Nom Sg => "servus",
         Gen Sg => "servi",
@@ -284,3 +285,648 @@ fn test_e_dekl_pl() {
         Vok Pl => "dies",
     };
 }
+
+#[test]
+fn test_i_dekl_mf_gemischt() {
+    test_deklination! {
+        "civis", "civis", M,
+
+        Nom Sg => "civis",
+        Gen Sg => "civis",
+        Dat Sg => "civi",
+        Akk Sg => "civem",
+        Abl Sg => "cive",
+        Vok Sg => "civis",
+
+        Nom Pl => "cives",
+        Gen Pl => "civium",
+        Dat Pl => "civibus",
+        Akk Pl => "cives",
+        Abl Pl => "civibus",
+        Vok Pl => "cives",
+    };
+    test_deklination! {
+        "urbs", "urbis", F,
+
+        Abl Sg => "urbe",
+        Gen Pl => "urbium",
+    };
+    test_deklination! {
+        "nox", "noctis", F,
+
+        Akk Sg => "noctem",
+        Gen Pl => "noctium",
+    };
+}
+
+#[test]
+fn test_i_dekl_mf_rein() {
+    test_deklination! {
+        "turris", "turris", F,
+
+        Nom Sg => "turris",
+        Gen Sg => "turris",
+        Dat Sg => "turri",
+        Akk Sg => "turrim",
+        Abl Sg => "turri",
+        Vok Sg => "turris",
+
+        Nom Pl => "turres",
+        Gen Pl => "turrium",
+        Dat Pl => "turribus",
+        Akk Pl => "turres",
+        Abl Pl => "turribus",
+        Vok Pl => "turres",
+    };
+}
+
+#[test]
+fn test_i_dekl_mf_pl() {
+    test_deklination! {
+        "hostes", "hostium", M,
+
+        Nom Pl => "hostes",
+        Gen Pl => "hostium",
+        Dat Pl => "hostibus",
+        Akk Pl => "hostes",
+        Abl Pl => "hostibus",
+        Vok Pl => "hostes",
+    };
+}
+
+#[test]
+fn test_i_dekl_n() {
+    test_deklination! {
+        "mare", "maris", N,
+
+        Nom Sg => "mare",
+        Gen Sg => "maris",
+        Dat Sg => "mari",
+        Akk Sg => "mare",
+        Abl Sg => "mari",
+        Vok Sg => "mare",
+
+        Nom Pl => "maria",
+        Gen Pl => "marium",
+        Dat Pl => "maribus",
+        Akk Pl => "maria",
+        Abl Pl => "maribus",
+        Vok Pl => "maria",
+    };
+    test_deklination! {
+        "animal", "animalis", N,
+
+        Abl Sg => "animali",
+        Nom Pl => "animalia",
+        Gen Pl => "animalium",
+    };
+}
+
+#[test]
+fn test_i_dekl_n_pl() {
+    test_deklination! {
+        "moenia", "moenium", N,
+
+        Nom Pl => "moenia",
+        Gen Pl => "moenium",
+        Dat Pl => "moenibus",
+        Akk Pl => "moenia",
+        Abl Pl => "moenibus",
+        Vok Pl => "moenia",
+    };
+}
+
+#[test]
+fn test_kons_dekl_ausnahme() {
+    test_deklination! {
+        "canis", "canis", M,
+
+        Abl Sg => "cane",
+        Gen Pl => "canum",
+    };
+    test_deklination! {
+        "pater", "patris", M,
+
+        Gen Pl => "patrum",
+    };
+}
+
+#[test]
+fn test_o_dekl_er() {
+    test_deklination! {
+        "puer", "pueri",
+
+        Nom Sg => "puer",
+        Gen Sg => "pueri",
+        Dat Sg => "puero",
+        Akk Sg => "puerum",
+        Abl Sg => "puero",
+        Vok Sg => "puer",
+
+        Nom Pl => "pueri",
+        Gen Pl => "puerorum",
+        Dat Pl => "pueris",
+        Akk Pl => "pueros",
+        Abl Pl => "pueris",
+        Vok Pl => "pueri",
+    };
+    test_deklination! {
+        "ager", "agri", M,
+
+        Nom Sg => "ager",
+        Gen Sg => "agri",
+        Akk Sg => "agrum",
+        Vok Sg => "ager",
+        Gen Pl => "agrorum",
+    };
+    test_deklination! {
+        "vir", "viri",
+
+        Vok Sg => "vir",
+        Akk Pl => "viros",
+    };
+}
+
+#[test]
+#[should_panic]
+fn test_o_dekl_er_falscher_genitiv() {
+    test_deklination!("ager", "aceri",);
+}
+
+#[test]
+fn test_u_dekl_n() {
+    test_deklination! {
+        "cornu", "cornus", N,
+
+        Nom Sg => "cornu",
+        Gen Sg => "cornus",
+        Dat Sg => "cornu",
+        Akk Sg => "cornu",
+        Abl Sg => "cornu",
+        Vok Sg => "cornu",
+
+        Nom Pl => "cornua",
+        Gen Pl => "cornuum",
+        Dat Pl => "cornibus",
+        Akk Pl => "cornua",
+        Abl Pl => "cornibus",
+        Vok Pl => "cornua",
+    };
+    test_deklination! {
+        "genu",
+
+        Gen Sg => "genus",
+        Nom Pl => "genua",
+    };
+}
+
+#[test]
+fn test_u_dekl_n_pl() {
+    test_deklination! {
+        "cornua", "cornuum", N,
+
+        Nom Pl => "cornua",
+        Gen Pl => "cornuum",
+        Dat Pl => "cornibus",
+        Akk Pl => "cornua",
+        Abl Pl => "cornibus",
+        Vok Pl => "cornua",
+    };
+}
+
+#[test]
+fn test_unregelmaessig() {
+    test_deklination! {
+        "domus",
+
+        Nom Sg => "domus",
+        Gen Sg => "domus",
+        Dat Sg => "domui",
+        Akk Sg => "domum",
+        Abl Sg => "domo",
+        Gen Pl => "domuum",
+        Akk Pl => "domos",
+    };
+    test_deklination! {
+        "deus", "dei",
+
+        Nom Pl => "di",
+        Dat Pl => "dis",
+    };
+    test_deklination! {
+        "vis",
+
+        Akk Sg => "vim",
+        Abl Sg => "vi",
+        Nom Pl => "vires",
+        Gen Pl => "virium",
+    };
+    test_deklination! {
+        "Iuppiter", "Iovis",
+
+        Akk Sg => "Iovem",
+        Vok Sg => "Iuppiter",
+    };
+    test_deklination! {
+        "bos", "bovis", F,
+
+        Gen Pl => "boum",
+        Dat Pl => "bubus",
+    };
+    test_deklination! {
+        "iter", "itineris", N,
+
+        Akk Sg => "iter",
+        Abl Sg => "itinere",
+        Nom Pl => "itinera",
+    };
+}
+
+#[test]
+fn test_unregelmaessig_fehlende_formen() {
+    let nomen = Nomen::parse(&WörterbuchEintrag {
+        nominativ: "vis",
+        genitiv: None,
+        genus: None,
+        ortsname: false,
+    })
+    .unwrap();
+    assert_eq!(nomen.deklinieren(Sg, Dat), None);
+
+    let nomen = Nomen::parse(&WörterbuchEintrag {
+        nominativ: "Iuppiter",
+        genitiv: None,
+        genus: None,
+        ortsname: false,
+    })
+    .unwrap();
+    assert_eq!(nomen.deklinieren(Pl, Nom), None);
+}
+
+#[test]
+fn test_a_dekl_gr() {
+    test_deklination! {
+        "Aeneas", "Aeneae",
+
+        Nom Sg => "Aeneas",
+        Gen Sg => "Aeneae",
+        Dat Sg => "Aeneae",
+        Akk Sg => "Aenean",
+        Abl Sg => "Aenea",
+        Vok Sg => "Aenea",
+    };
+    test_deklination! {
+        "Anchises", "Anchisae",
+
+        Nom Sg => "Anchises",
+        Gen Sg => "Anchisae",
+        Akk Sg => "Anchisen",
+        Abl Sg => "Anchise",
+        Vok Sg => "Anchise",
+    };
+    test_deklination! {
+        "epitome", "epitomes",
+
+        Nom Sg => "epitome",
+        Gen Sg => "epitomes",
+        Dat Sg => "epitomae",
+        Akk Sg => "epitomen",
+        Abl Sg => "epitome",
+        Nom Pl => "epitomae",
+        Gen Pl => "epitomarum",
+    };
+}
+
+#[test]
+fn test_o_dekl_gr() {
+    test_deklination! {
+        "Delos", "Deli", F,
+
+        Nom Sg => "Delos",
+        Gen Sg => "Deli",
+        Dat Sg => "Delo",
+        Akk Sg => "Delon",
+        Abl Sg => "Delo",
+        Vok Sg => "Dele",
+    };
+    test_deklination! {
+        "barbiton", "barbiti",
+
+        Nom Sg => "barbiton",
+        Akk Sg => "barbiton",
+        Abl Sg => "barbito",
+        Nom Pl => "barbita",
+        Gen Pl => "barbitorum",
+    };
+}
+
+#[test]
+fn test_kons_dekl_gr() {
+    test_deklination! {
+        "lampas", "lampados", F,
+
+        Nom Sg => "lampas",
+        Gen Sg => "lampados",
+        Dat Sg => "lampadi",
+        Akk Sg => "lampada",
+        Abl Sg => "lampade",
+        Nom Pl => "lampades",
+        Akk Pl => "lampadas",
+    };
+    test_deklination! {
+        "heros", "heroos", M,
+
+        Nom Sg => "heros",
+        Dat Sg => "heroi",
+        Akk Sg => "heroa",
+    };
+    test_deklination! {
+        "gigas", "gigantos", M,
+
+        Akk Sg => "giganta",
+        Nom Pl => "gigantes",
+    };
+
+    let Err(ParseFehler::KeineDeklination(ablehnungen)) = Nomen::parse(&WörterbuchEintrag {
+        nominativ: "rosa",
+        genitiv: Some("lampados"),
+        genus: Some(F),
+        ortsname: false,
+    }) else {
+        panic!("rosa, lampados should not parse");
+    };
+    let ablehnung = ablehnungen
+        .iter()
+        .find(|ablehnung| {
+            ablehnung
+                .get_name()
+                .starts_with("griechische konsonantische")
+        })
+        .unwrap();
+    assert_eq!(ablehnung.get_grund(), Ablehnungsgrund::NominativPasstNicht);
+}
+
+#[test]
+fn test_lokativ() {
+    for (nominativ, genitiv, genus, numerus, lokativ) in [
+        ("Roma", "Romae", F, Sg, "Romae"),
+        ("Corinthus", "Corinthi", F, Sg, "Corinthi"),
+        ("Carthago", "Carthaginis", F, Sg, "Carthagine"),
+        ("Athenae", "Athenarum", F, Pl, "Athenis"),
+        ("Delphi", "Delphorum", M, Pl, "Delphis"),
+    ] {
+        let nomen = Nomen::parse(&WörterbuchEintrag {
+            nominativ,
+            genitiv: Some(genitiv),
+            genus: Some(genus),
+            ortsname: true,
+        })
+        .unwrap();
+        assert_eq!(nomen.deklinieren(numerus, Lok).unwrap(), lokativ);
+    }
+
+    for (nominativ, genitiv, genus, lokativ) in [
+        ("domus", "domus", F, "domi"),
+        ("rus", "ruris", N, "ruri"),
+        ("humus", "humi", F, "humi"),
+    ] {
+        let nomen = Nomen::parse(&WörterbuchEintrag {
+            nominativ,
+            genitiv: Some(genitiv),
+            genus: Some(genus),
+            ortsname: false,
+        })
+        .unwrap();
+        assert_eq!(nomen.deklinieren(Sg, Lok).unwrap(), lokativ);
+    }
+}
+
+#[test]
+fn test_kein_lokativ() {
+    let nomen = Nomen::parse(&WörterbuchEintrag {
+        nominativ: "servus",
+        genitiv: Some("servi"),
+        genus: None,
+        ortsname: false,
+    })
+    .unwrap();
+    assert_eq!(nomen.deklinieren(Sg, Lok), None);
+}
+
+#[test]
+fn test_mehrdeutig() {
+    let eintrag = WörterbuchEintrag {
+        nominativ: "manus",
+        genitiv: None,
+        genus: Some(F),
+        ortsname: false,
+    };
+
+    let Err(ParseFehler::Mehrdeutig(kandidaten)) = Nomen::parse(&eintrag) else {
+        panic!("manus without genitive should be ambiguous");
+    };
+    assert_eq!(kandidaten.len(), 2);
+    assert!(kandidaten.iter().all(|kandidat| kandidat.get_genus() == F));
+
+    let nomen = kandidaten
+        .into_iter()
+        .find(|kandidat| kandidat.get_grund().starts_with("u-Deklination"))
+        .unwrap()
+        .into_nomen();
+    assert_eq!(nomen.deklinieren(Sg, Abl).unwrap(), "manu");
+
+    test_deklination! {
+        "manus", "manus", F,
+
+        Abl Sg => "manu",
+    };
+}
+
+#[test]
+fn test_kons_dekl_vokalstamm() {
+    test_deklination! {
+        "sus", "suis", M,
+
+        Gen Sg => "suis",
+        Akk Sg => "suem",
+        Abl Sg => "sue",
+        Nom Pl => "sues",
+        Gen Pl => "suum",
+    }
+    test_deklination! {
+        "grus", "gruis", F,
+
+        Akk Sg => "gruem",
+        Nom Pl => "grues",
+        Akk Pl => "grues",
+    }
+}
+
+#[test]
+fn test_eindeutig() {
+    for (nominativ, genitiv, genus) in [
+        ("mare", "maris", N),
+        ("civis", "civis", M),
+        ("cornua", "cornuum", N),
+        ("moenia", "moenium", N),
+        ("sus", "suis", M),
+        ("grus", "gruis", F),
+    ] {
+        let kandidaten = Nomen::kandidaten(&WörterbuchEintrag {
+            nominativ,
+            genitiv: Some(genitiv),
+            genus: Some(genus),
+            ortsname: false,
+        });
+        assert_eq!(kandidaten.len(), 1, "{nominativ}: {kandidaten:?}");
+    }
+}
+
+#[test]
+fn test_parse_fehler() {
+    let Err(ParseFehler::KeineDeklination(ablehnungen)) = Nomen::parse(&WörterbuchEintrag {
+        nominativ: "senator",
+        genitiv: None,
+        genus: Some(M),
+        ortsname: false,
+    }) else {
+        panic!("senator without genitive should not parse");
+    };
+    let ablehnung = ablehnungen
+        .iter()
+        .find(|ablehnung| ablehnung.get_name().starts_with("konsonantische"))
+        .unwrap();
+    assert_eq!(ablehnung.get_grund(), Ablehnungsgrund::GenitivFehlt);
+
+    let Err(ParseFehler::KeineDeklination(ablehnungen)) = Nomen::parse(&WörterbuchEintrag {
+        nominativ: "templum",
+        genitiv: Some("templi"),
+        genus: Some(F),
+        ortsname: false,
+    }) else {
+        panic!("templum should not parse as femininum");
+    };
+    assert!(ablehnungen
+        .iter()
+        .any(|ablehnung| ablehnung.get_grund() == Ablehnungsgrund::GenusNichtErlaubt(F)));
+
+    let Err(ParseFehler::KeineDeklination(ablehnungen)) = Nomen::parse(&WörterbuchEintrag {
+        nominativ: "servus",
+        genitiv: Some("servae"),
+        genus: None,
+        ortsname: false,
+    }) else {
+        panic!("servus, servae should not parse");
+    };
+    assert!(ablehnungen
+        .iter()
+        .any(|ablehnung| ablehnung.get_grund() == Ablehnungsgrund::GenitivPasstNicht));
+
+    // Ein Nomen aus dem Lexikon wird mit falschem Genus nicht regelmäßig dekliniert
+    let Err(ParseFehler::KeineDeklination(ablehnungen)) = Nomen::parse(&WörterbuchEintrag {
+        nominativ: "domus",
+        genitiv: Some("domus"),
+        genus: Some(M),
+        ortsname: false,
+    }) else {
+        panic!("domus should not parse as maskulinum");
+    };
+    assert_eq!(ablehnungen.len(), 1);
+    assert_eq!(ablehnungen[0].get_name(), "unregelmäßiges Nomen");
+    assert_eq!(
+        ablehnungen[0].get_grund(),
+        Ablehnungsgrund::GenusNichtErlaubt(M)
+    );
+
+    let fehler: Box<dyn std::error::Error> = Box::new(
+        Nomen::parse(&WörterbuchEintrag {
+            nominativ: "servus",
+            genitiv: Some("servae"),
+            genus: None,
+            ortsname: false,
+        })
+        .err()
+        .unwrap(),
+    );
+    assert!(fehler
+        .to_string()
+        .starts_with("keine passende Deklination: "));
+    assert!(fehler
+        .to_string()
+        .contains("(Endung des Genitivs passt nicht)"));
+}
+
+#[test]
+fn test_klassifikation() {
+    use Deklinationsklasse as K;
+
+    for (nominativ, genitiv, genus, klasse, stamm, plural) in [
+        ("rosa", "rosae", F, K::ADeklination, "ros", false),
+        ("servus", "servi", M, K::ODeklination, "serv", false),
+        ("puer", "pueri", M, K::ODeklination, "puer", false),
+        ("arma", "armorum", N, K::ODeklination, "arm", true),
+        ("senatus", "senatus", M, K::UDeklination, "senat", false),
+        ("res", "rei", F, K::EDeklination, "r", false),
+        (
+            "rex",
+            "regis",
+            M,
+            K::KonsonantischeDeklination,
+            "reg",
+            false,
+        ),
+        ("civis", "civis", M, K::IDeklination, "civ", false),
+        ("moenia", "moenium", N, K::IDeklination, "moen", true),
+        ("domus", "domus", F, K::Unregelmäßig, "dom", false),
+    ] {
+        let Ok(nomen) = Nomen::parse(&WörterbuchEintrag {
+            nominativ,
+            genitiv: Some(genitiv),
+            genus: Some(genus),
+            ortsname: false,
+        }) else {
+            panic!("failed to parse {nominativ}, {genitiv}");
+        };
+        let klassifikation = nomen.get_klassifikation();
+        assert_eq!(klassifikation.get_klasse(), klasse, "{nominativ}");
+        assert_eq!(klassifikation.get_stamm(), stamm, "{nominativ}");
+        assert_eq!(klassifikation.is_plural(), plural, "{nominativ}");
+    }
+}
+
+#[test]
+fn test_varianten() {
+    for (nominativ, genitiv, genus, numerus, kasus, varianten) in [
+        ("civis", "civis", M, Pl, Akk, &["cives", "civis"][..]),
+        ("turris", "turris", F, Sg, Akk, &["turrim", "turrem"]),
+        ("turris", "turris", F, Sg, Abl, &["turri", "turre"]),
+        ("parens", "parentis", M, Pl, Gen, &["parentium", "parentum"]),
+        ("urbs", "urbis", F, Pl, Gen, &["urbium"]),
+        ("imber", "imbris", M, Pl, Gen, &["imbrium"]),
+        ("linter", "lintris", F, Pl, Gen, &["lintrium"]),
+        ("venter", "ventris", M, Pl, Gen, &["ventrium"]),
+        ("pater", "patris", M, Pl, Gen, &["patrum"]),
+        ("filia", "filiae", F, Pl, Dat, &["filiis", "filiabus"]),
+        ("rosa", "rosae", F, Pl, Dat, &["rosis"]),
+        ("filius", "filii", M, Sg, Gen, &["filii", "fili"]),
+        ("ingenium", "ingenii", N, Sg, Gen, &["ingenii", "ingeni"]),
+        ("domus", "domus", F, Pl, Gen, &["domuum", "domorum"]),
+        ("deus", "dei", M, Pl, Nom, &["di", "dei", "dii"]),
+        ("deus", "dei", M, Pl, Abl, &["dis", "deis", "diis"]),
+    ] {
+        let Ok(nomen) = Nomen::parse(&WörterbuchEintrag {
+            nominativ,
+            genitiv: Some(genitiv),
+            genus: Some(genus),
+            ortsname: false,
+        }) else {
+            panic!("failed to parse {nominativ}, {genitiv}");
+        };
+        assert_eq!(nomen.varianten(numerus, kasus), varianten, "{nominativ}");
+        assert_eq!(
+            nomen.deklinieren(numerus, kasus).as_deref(),
+            Some(varianten[0]),
+            "{nominativ}"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::grammatik::{Genus, Kasus, Numerus};
 
-use super::StammDeklination;
+use super::{Deklinationsklasse, StammDeklination};
 
 pub struct UDeklination<'a> {
     stamm: &'a str,
@@ -8,13 +8,13 @@ pub struct UDeklination<'a> {
 }
 
 impl<'a> StammDeklination<'a> for UDeklination<'a> {
+    const KLASSE: Deklinationsklasse = Deklinationsklasse::UDeklination;
+    const GRUND: &'static str = "u-Deklination: Nominativ auf -us";
     const DEFAULT_GENUS: Option<Genus> = Some(Genus::Maskulinum);
     const ALLOWS_MASKULINUM: bool = true;
     const ALLOWS_FEMININUM: bool = true;
     const ALLOWS_NEUTRUM: bool = false;
 
-    const REQUIRE_GENITIVE_SINGULAR: bool = true;
-
     fn new(stamm: &'a str, plural: bool) -> Self {
         Self { stamm, plural }
     }
@@ -35,6 +35,7 @@ impl<'a> StammDeklination<'a> for UDeklination<'a> {
                 Kasus::Dativ => "ui",
                 Kasus::Akkusativ => "um",
                 Kasus::Ablativ => "u",
+                Kasus::Lokativ => "u",
             },
             Numerus::Plural => match kasus {
                 Kasus::Nominativ | Kasus::Vokativ => "us",
@@ -42,6 +43,7 @@ impl<'a> StammDeklination<'a> for UDeklination<'a> {
                 Kasus::Dativ => "ibus",
                 Kasus::Akkusativ => "us",
                 Kasus::Ablativ => "ibus",
+                Kasus::Lokativ => "ibus",
             },
         })
     }
@@ -0,0 +1,50 @@
+use crate::grammatik::{Genus, Kasus, Numerus};
+
+use super::{Deklinationsklasse, StammDeklination};
+
+pub struct UDeklinationNeutrum<'a> {
+    stamm: &'a str,
+    plural: bool,
+}
+
+impl<'a> StammDeklination<'a> for UDeklinationNeutrum<'a> {
+    const KLASSE: Deklinationsklasse = Deklinationsklasse::UDeklination;
+    const GRUND: &'static str = "u-Deklination (Neutrum): Nominativ auf -u";
+    const DEFAULT_GENUS: Option<Genus> = Some(Genus::Neutrum);
+    const ALLOWS_MASKULINUM: bool = false;
+    const ALLOWS_FEMININUM: bool = false;
+    const ALLOWS_NEUTRUM: bool = true;
+
+    fn new(stamm: &'a str, plural: bool) -> Self {
+        Self { stamm, plural }
+    }
+
+    fn get_stamm(&self) -> &'a str {
+        self.stamm
+    }
+
+    fn is_plural(&self) -> bool {
+        self.plural
+    }
+
+    fn get_endung(numerus: Numerus, kasus: Kasus) -> Option<&'static str> {
+        Some(match numerus {
+            Numerus::Singular => match kasus {
+                Kasus::Nominativ | Kasus::Vokativ => "u",
+                Kasus::Genitiv => "us",
+                Kasus::Dativ => "u",
+                Kasus::Akkusativ => "u",
+                Kasus::Ablativ => "u",
+                Kasus::Lokativ => "u",
+            },
+            Numerus::Plural => match kasus {
+                Kasus::Nominativ | Kasus::Vokativ => "ua",
+                Kasus::Genitiv => "uum",
+                Kasus::Dativ => "ibus",
+                Kasus::Akkusativ => "ua",
+                Kasus::Ablativ => "ibus",
+                Kasus::Lokativ => "ibus",
+            },
+        })
+    }
+}
@@ -0,0 +1,239 @@
+use crate::grammatik::{Genus, Kasus, Numerus};
+
+use super::{Ablehnungsgrund, Deklination, Deklinationsklasse, Klassifikation, WörterbuchEintrag};
+
+// Formen in der Reihenfolge Nominativ, Genitiv, Dativ, Akkusativ, Ablativ, Vokativ, Lokativ,
+// jeweils mit der bevorzugten Variante zuerst
+type Formen = [&'static [&'static str]; 7];
+
+struct Paradigma {
+    nominativ: &'static str,
+    genitiv: &'static str,
+    stamm: &'static str,
+    genera: &'static [Genus],
+    singular: Formen,
+    plural: Formen,
+}
+
+static PARADIGMEN: [Paradigma; 7] = [
+    Paradigma {
+        nominativ: "domus",
+        genitiv: "domus",
+        stamm: "dom",
+        genera: &[Genus::Femininum],
+        singular: [
+            &["domus"],
+            &["domus"],
+            &["domui"],
+            &["domum"],
+            &["domo", "domu"],
+            &["domus"],
+            &["domi"],
+        ],
+        plural: [
+            &["domus"],
+            &["domuum", "domorum"],
+            &["domibus"],
+            &["domos", "domus"],
+            &["domibus"],
+            &["domus"],
+            &[],
+        ],
+    },
+    Paradigma {
+        nominativ: "deus",
+        genitiv: "dei",
+        stamm: "de",
+        genera: &[Genus::Maskulinum],
+        singular: [
+            &["deus"],
+            &["dei"],
+            &["deo"],
+            &["deum"],
+            &["deo"],
+            &["deus"],
+            &[],
+        ],
+        plural: [
+            &["di", "dei", "dii"],
+            &["deorum"],
+            &["dis", "deis", "diis"],
+            &["deos"],
+            &["dis", "deis", "diis"],
+            &["di", "dei", "dii"],
+            &[],
+        ],
+    },
+    Paradigma {
+        nominativ: "vis",
+        genitiv: "vis",
+        stamm: "vi",
+        genera: &[Genus::Femininum],
+        singular: [&["vis"], &[], &[], &["vim"], &["vi"], &["vis"], &[]],
+        plural: [
+            &["vires"],
+            &["virium"],
+            &["viribus"],
+            &["vires"],
+            &["viribus"],
+            &["vires"],
+            &[],
+        ],
+    },
+    Paradigma {
+        nominativ: "Iuppiter",
+        genitiv: "Iovis",
+        stamm: "Iov",
+        genera: &[Genus::Maskulinum],
+        singular: [
+            &["Iuppiter"],
+            &["Iovis"],
+            &["Iovi"],
+            &["Iovem"],
+            &["Iove"],
+            &["Iuppiter"],
+            &[],
+        ],
+        plural: [&[]; 7],
+    },
+    Paradigma {
+        nominativ: "bos",
+        genitiv: "bovis",
+        stamm: "bov",
+        genera: &[Genus::Maskulinum, Genus::Femininum],
+        singular: [
+            &["bos"],
+            &["bovis"],
+            &["bovi"],
+            &["bovem"],
+            &["bove"],
+            &["bos"],
+            &[],
+        ],
+        plural: [
+            &["boves"],
+            &["boum"],
+            &["bubus"],
+            &["boves"],
+            &["bubus"],
+            &["boves"],
+            &[],
+        ],
+    },
+    Paradigma {
+        nominativ: "rus",
+        genitiv: "ruris",
+        stamm: "rur",
+        genera: &[Genus::Neutrum],
+        singular: [
+            &["rus"],
+            &["ruris"],
+            &["ruri"],
+            &["rus"],
+            &["rure"],
+            &["rus"],
+            &["ruri"],
+        ],
+        plural: [
+            &["rura"],
+            &["rurum"],
+            &["ruribus"],
+            &["rura"],
+            &["ruribus"],
+            &["rura"],
+            &[],
+        ],
+    },
+    Paradigma {
+        nominativ: "iter",
+        genitiv: "itineris",
+        stamm: "itiner",
+        genera: &[Genus::Neutrum],
+        singular: [
+            &["iter"],
+            &["itineris"],
+            &["itineri"],
+            &["iter"],
+            &["itinere"],
+            &["iter"],
+            &[],
+        ],
+        plural: [
+            &["itinera"],
+            &["itinerum"],
+            &["itineribus"],
+            &["itinera"],
+            &["itineribus"],
+            &["itinera"],
+            &[],
+        ],
+    },
+];
+
+pub struct UnregelmäßigesNomen {
+    paradigma: &'static Paradigma,
+}
+
+impl UnregelmäßigesNomen {
+    pub const GRUND: &'static str = "unregelmäßiges Nomen";
+
+    // None, wenn der Eintrag nicht im Lexikon steht. Ein bekanntes Nomen mit falschem Genus wird
+    // abgelehnt, statt regelmäßig dekliniert zu werden: domus, m.
+    pub fn parse(eintrag: &WörterbuchEintrag) -> Option<Result<(Genus, Self), Ablehnungsgrund>> {
+        let paradigma = PARADIGMEN
+            .iter()
+            .find(|paradigma| paradigma.nominativ == eintrag.nominativ)?;
+
+        if matches!(eintrag.genitiv, Some(genitiv) if genitiv != paradigma.genitiv) {
+            return None;
+        }
+
+        let genus = match eintrag.genus {
+            None => paradigma.genera[0],
+            Some(genus) if paradigma.genera.contains(&genus) => genus,
+            Some(genus) => return Some(Err(Ablehnungsgrund::GenusNichtErlaubt(genus))),
+        };
+
+        Some(Ok((genus, Self { paradigma })))
+    }
+
+    fn formen(&self, numerus: Numerus, kasus: Kasus) -> &'static [&'static str] {
+        let formen = match numerus {
+            Numerus::Singular => &self.paradigma.singular,
+            Numerus::Plural => &self.paradigma.plural,
+        };
+
+        match kasus {
+            Kasus::Nominativ => formen[0],
+            Kasus::Genitiv => formen[1],
+            Kasus::Dativ => formen[2],
+            Kasus::Akkusativ => formen[3],
+            Kasus::Ablativ => formen[4],
+            Kasus::Vokativ => formen[5],
+            Kasus::Lokativ => formen[6],
+        }
+    }
+}
+
+impl Deklination for UnregelmäßigesNomen {
+    fn deklinieren(&self, numerus: Numerus, kasus: Kasus) -> Option<String> {
+        self.formen(numerus, kasus)
+            .first()
+            .map(|&form| String::from(form))
+    }
+
+    fn varianten(&self, numerus: Numerus, kasus: Kasus) -> Vec<String> {
+        self.formen(numerus, kasus)
+            .iter()
+            .map(|&form| String::from(form))
+            .collect()
+    }
+
+    fn klassifikation(&self) -> Klassifikation<'_> {
+        Klassifikation {
+            klasse: Deklinationsklasse::Unregelmäßig,
+            stamm: self.paradigma.stamm,
+            plural: false,
+        }
+    }
+}
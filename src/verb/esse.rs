@@ -0,0 +1,31 @@
+use crate::grammatik::{Modus, Numerus, Person, Tempus};
+
+use super::index;
+
+// Formen von esse für die zusammengesetzten Formen des Passivs
+type Formen = [&'static str; 6];
+
+const PRÄSENS: Formen = ["sum", "es", "est", "sumus", "estis", "sunt"];
+const IMPERFEKT: Formen = ["eram", "eras", "erat", "eramus", "eratis", "erant"];
+const FUTUR: Formen = ["ero", "eris", "erit", "erimus", "eritis", "erunt"];
+const KONJUNKTIV_PRÄSENS: Formen = ["sim", "sis", "sit", "simus", "sitis", "sint"];
+const KONJUNKTIV_IMPERFEKT: Formen = ["essem", "esses", "esset", "essemus", "essetis", "essent"];
+
+pub fn get_form(
+    tempus: Tempus,
+    modus: Modus,
+    person: Person,
+    numerus: Numerus,
+) -> Option<&'static str> {
+    let formen = match (modus, tempus) {
+        (Modus::Indikativ, Tempus::Präsens) => &PRÄSENS,
+        (Modus::Indikativ, Tempus::Imperfekt) => &IMPERFEKT,
+        (Modus::Indikativ, Tempus::Futur) => &FUTUR,
+        (Modus::Konjunktiv, Tempus::Präsens) => &KONJUNKTIV_PRÄSENS,
+        (Modus::Konjunktiv, Tempus::Imperfekt) => &KONJUNKTIV_IMPERFEKT,
+        (Modus::Konjunktiv, Tempus::Futur)
+        | (Modus::Imperativ, _)
+        | (_, Tempus::Perfekt | Tempus::Plusquamperfekt | Tempus::FuturII) => return None,
+    };
+    Some(formen[index(person, numerus)])
+}
@@ -0,0 +1,156 @@
+use crate::grammatik::{GenusVerbi, Numerus, Person, Tempus};
+
+use super::{index, Klassifikation, Konjugationsklasse};
+
+// Imperativ I (Präsens) nur in der zweiten Person, Imperativ II (Futur) in der zweiten und dritten
+type Endungen = [Option<&'static str>; 6];
+
+const IMPERATIV_I_A: Endungen = [None, Some("a"), None, None, Some("ate"), None];
+const IMPERATIV_I_E: Endungen = [None, Some("e"), None, None, Some("ete"), None];
+const IMPERATIV_I_KONSONANTISCH: Endungen = [None, Some("e"), None, None, Some("ite"), None];
+const IMPERATIV_I_I: Endungen = [None, Some("i"), None, None, Some("ite"), None];
+
+const IMPERATIV_I_PASSIV_A: Endungen = [None, Some("are"), None, None, Some("amini"), None];
+const IMPERATIV_I_PASSIV_E: Endungen = [None, Some("ere"), None, None, Some("emini"), None];
+const IMPERATIV_I_PASSIV_KONSONANTISCH: Endungen =
+    [None, Some("ere"), None, None, Some("imini"), None];
+const IMPERATIV_I_PASSIV_I: Endungen = [None, Some("ire"), None, None, Some("imini"), None];
+
+const IMPERATIV_II_A: Endungen = [
+    None,
+    Some("ato"),
+    Some("ato"),
+    None,
+    Some("atote"),
+    Some("anto"),
+];
+const IMPERATIV_II_E: Endungen = [
+    None,
+    Some("eto"),
+    Some("eto"),
+    None,
+    Some("etote"),
+    Some("ento"),
+];
+const IMPERATIV_II_KONSONANTISCH: Endungen = [
+    None,
+    Some("ito"),
+    Some("ito"),
+    None,
+    Some("itote"),
+    Some("unto"),
+];
+const IMPERATIV_II_I: Endungen = [
+    None,
+    Some("ito"),
+    Some("ito"),
+    None,
+    Some("itote"),
+    Some("iunto"),
+];
+
+const IMPERATIV_II_PASSIV_A: Endungen =
+    [None, Some("ator"), Some("ator"), None, None, Some("antor")];
+const IMPERATIV_II_PASSIV_E: Endungen =
+    [None, Some("etor"), Some("etor"), None, None, Some("entor")];
+const IMPERATIV_II_PASSIV_KONSONANTISCH: Endungen =
+    [None, Some("itor"), Some("itor"), None, None, Some("untor")];
+const IMPERATIV_II_PASSIV_I: Endungen =
+    [None, Some("itor"), Some("itor"), None, None, Some("iuntor")];
+
+// dic, duc, fac und ihre Komposita (edic, educ, calefac); conficio bildet regelmäßig confice
+fn ist_kurzform(klassifikation: Klassifikation) -> bool {
+    let stamm = klassifikation.get_stamm();
+    match klassifikation.get_klasse() {
+        Konjugationsklasse::KonsonantischeKonjugation => {
+            stamm.ends_with("dic") || stamm.ends_with("duc")
+        }
+        Konjugationsklasse::KurzvokalischeIKonjugation => stamm.ends_with("fac"),
+        _ => false,
+    }
+}
+
+// fer, ferte, ferto, fertote; die übrigen Formen wie in der konsonantischen Konjugation
+fn get_athematische_endung(
+    tempus: Tempus,
+    genus_verbi: GenusVerbi,
+    person: Person,
+    numerus: Numerus,
+) -> Option<&'static str> {
+    Some(match (tempus, genus_verbi, person, numerus) {
+        (Tempus::Präsens, GenusVerbi::Aktiv, Person::Zweite, Numerus::Singular) => "",
+        (Tempus::Präsens, GenusVerbi::Aktiv, Person::Zweite, Numerus::Plural) => "te",
+        (Tempus::Präsens, GenusVerbi::Passiv, Person::Zweite, Numerus::Singular) => "re",
+        (Tempus::Futur, GenusVerbi::Aktiv, Person::Zweite | Person::Dritte, Numerus::Singular) => {
+            "to"
+        }
+        (Tempus::Futur, GenusVerbi::Aktiv, Person::Zweite, Numerus::Plural) => "tote",
+        (Tempus::Futur, GenusVerbi::Passiv, Person::Zweite | Person::Dritte, Numerus::Singular) => {
+            "tor"
+        }
+        _ => return None,
+    })
+}
+
+pub fn get_endung(
+    klassifikation: Klassifikation,
+    athematisch: bool,
+    tempus: Tempus,
+    genus_verbi: GenusVerbi,
+    person: Person,
+    numerus: Numerus,
+) -> Option<&'static str> {
+    use Konjugationsklasse as K;
+
+    if let (Tempus::Präsens, GenusVerbi::Aktiv, Person::Zweite, Numerus::Singular) =
+        (tempus, genus_verbi, person, numerus)
+    {
+        if ist_kurzform(klassifikation) {
+            return Some("");
+        }
+    }
+    if athematisch {
+        if let Some(endung) = get_athematische_endung(tempus, genus_verbi, person, numerus) {
+            return Some(endung);
+        }
+    }
+
+    let endungen = match (genus_verbi, tempus, klassifikation.get_klasse()) {
+        (GenusVerbi::Aktiv, Tempus::Präsens, K::AKonjugation) => &IMPERATIV_I_A,
+        (GenusVerbi::Aktiv, Tempus::Präsens, K::EKonjugation) => &IMPERATIV_I_E,
+        (
+            GenusVerbi::Aktiv,
+            Tempus::Präsens,
+            K::KonsonantischeKonjugation | K::KurzvokalischeIKonjugation,
+        ) => &IMPERATIV_I_KONSONANTISCH,
+        (GenusVerbi::Aktiv, Tempus::Präsens, K::IKonjugation) => &IMPERATIV_I_I,
+        (GenusVerbi::Aktiv, Tempus::Futur, K::AKonjugation) => &IMPERATIV_II_A,
+        (GenusVerbi::Aktiv, Tempus::Futur, K::EKonjugation) => &IMPERATIV_II_E,
+        (GenusVerbi::Aktiv, Tempus::Futur, K::KonsonantischeKonjugation) => {
+            &IMPERATIV_II_KONSONANTISCH
+        }
+        (GenusVerbi::Aktiv, Tempus::Futur, K::KurzvokalischeIKonjugation | K::IKonjugation) => {
+            &IMPERATIV_II_I
+        }
+        (GenusVerbi::Passiv, Tempus::Präsens, K::AKonjugation) => &IMPERATIV_I_PASSIV_A,
+        (GenusVerbi::Passiv, Tempus::Präsens, K::EKonjugation) => &IMPERATIV_I_PASSIV_E,
+        (
+            GenusVerbi::Passiv,
+            Tempus::Präsens,
+            K::KonsonantischeKonjugation | K::KurzvokalischeIKonjugation,
+        ) => &IMPERATIV_I_PASSIV_KONSONANTISCH,
+        (GenusVerbi::Passiv, Tempus::Präsens, K::IKonjugation) => &IMPERATIV_I_PASSIV_I,
+        (GenusVerbi::Passiv, Tempus::Futur, K::AKonjugation) => &IMPERATIV_II_PASSIV_A,
+        (GenusVerbi::Passiv, Tempus::Futur, K::EKonjugation) => &IMPERATIV_II_PASSIV_E,
+        (GenusVerbi::Passiv, Tempus::Futur, K::KonsonantischeKonjugation) => {
+            &IMPERATIV_II_PASSIV_KONSONANTISCH
+        }
+        (GenusVerbi::Passiv, Tempus::Futur, K::KurzvokalischeIKonjugation | K::IKonjugation) => {
+            &IMPERATIV_II_PASSIV_I
+        }
+        (_, Tempus::Imperfekt | Tempus::Perfekt | Tempus::Plusquamperfekt | Tempus::FuturII, _) => {
+            return None
+        }
+    };
+    endungen[index(person, numerus)]
+}
@@ -0,0 +1,345 @@
+use std::fmt;
+
+use crate::{
+    adjektiv::ao_dekl::AODeklination,
+    grammatik::{self, test_form, Genus, GenusVerbi, Kasus, Modus, Numerus, Person, Tempus},
+};
+
+use self::stammformen::{test_stammform, Stammform};
+
+mod esse;
+mod imperativ;
+mod perfekt;
+mod praesens;
+mod stammformen;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub enum Konjugationsklasse {
+    AKonjugation,
+    EKonjugation,
+    KonsonantischeKonjugation,
+    KurzvokalischeIKonjugation, // capio, capere
+    IKonjugation,
+}
+
+impl Konjugationsklasse {
+    const ALLE: [Self; 5] = [
+        Self::AKonjugation,
+        Self::EKonjugation,
+        Self::KonsonantischeKonjugation,
+        Self::KurzvokalischeIKonjugation,
+        Self::IKonjugation,
+    ];
+
+    fn get_name(self) -> &'static str {
+        match self {
+            Self::AKonjugation => "a-Konjugation",
+            Self::EKonjugation => "e-Konjugation",
+            Self::KonsonantischeKonjugation => "konsonantische Konjugation",
+            Self::KurzvokalischeIKonjugation => "kurzvokalische i-Konjugation",
+            Self::IKonjugation => "i-Konjugation",
+        }
+    }
+
+    // Endungen der ersten Person Singular Präsens und des Infinitivs
+    fn get_muster(self) -> (&'static str, &'static str) {
+        match self {
+            Self::AKonjugation => ("o", "are"),
+            Self::EKonjugation => ("eo", "ere"),
+            Self::KonsonantischeKonjugation => ("o", "ere"),
+            Self::KurzvokalischeIKonjugation => ("io", "ere"),
+            Self::IKonjugation => ("io", "ire"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Klassifikation<'a> {
+    klasse: Konjugationsklasse,
+    stamm: &'a str, // Ohne Kennvokal: laud, mon, ag, cap, aud
+}
+
+impl<'a> Klassifikation<'a> {
+    pub fn get_klasse(&self) -> Konjugationsklasse {
+        self.klasse
+    }
+
+    pub fn get_stamm(&self) -> &'a str {
+        self.stamm
+    }
+}
+
+// Die Reihenfolge gibt an, wie weit ein Muster zum Eintrag gepasst hat
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Ablehnungsgrund {
+    ErsteFormPasstNicht,
+    InfinitivPasstNicht,
+    PerfektPasstNicht,
+    SupinumPasstNicht,
+}
+
+impl fmt::Display for Ablehnungsgrund {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::ErsteFormPasstNicht => write!(f, "erste Form passt nicht"),
+            Self::InfinitivPasstNicht => write!(f, "Infinitiv passt nicht"),
+            Self::PerfektPasstNicht => write!(f, "Perfekt passt nicht"),
+            Self::SupinumPasstNicht => write!(f, "Supinum passt nicht"),
+        }
+    }
+}
+
+pub type Ablehnung = grammatik::Ablehnung<Ablehnungsgrund>;
+
+#[derive(Debug)]
+pub enum ParseFehler {
+    KeineKonjugation(Vec<Ablehnung>),
+}
+
+impl fmt::Display for ParseFehler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::KeineKonjugation(ref ablehnungen) => {
+                write!(f, "keine passende Konjugation: ")?;
+                Ablehnung::fmt_liste(ablehnungen, f)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseFehler {}
+
+#[derive(Debug)]
+pub struct WörterbuchEintrag<'a> {
+    erste_form: &'a str, // Erste Person Singular Präsens Indikativ Aktiv
+    perfekt: Option<&'a str>,
+    supinum: Option<&'a str>,
+    infinitiv: &'a str,
+}
+
+impl<'a> WörterbuchEintrag<'a> {
+    pub fn from_two(erste_form: &'a str, infinitiv: &'a str) -> Self {
+        Self {
+            erste_form,
+            perfekt: None,
+            supinum: None,
+            infinitiv,
+        }
+    }
+
+    // Die vier Stammformen in der Reihenfolge des Wörterbuchs: laudo, laudavi, laudatum, laudare
+    pub fn from_four(
+        erste_form: &'a str,
+        perfekt: &'a str,
+        supinum: &'a str,
+        infinitiv: &'a str,
+    ) -> Self {
+        Self {
+            erste_form,
+            perfekt: Some(perfekt),
+            supinum: Some(supinum),
+            infinitiv,
+        }
+    }
+}
+
+// Position einer Form in den Endungstabellen: o, s, t, mus, tis, nt
+fn index(person: Person, numerus: Numerus) -> usize {
+    let person = match person {
+        Person::Erste => 0,
+        Person::Zweite => 1,
+        Person::Dritte => 2,
+    };
+    match numerus {
+        Numerus::Singular => person,
+        Numerus::Plural => person + 3,
+    }
+}
+
+pub struct Verb<'a> {
+    klassifikation: Klassifikation<'a>,
+    perfektstamm: Option<&'a str>,               // laudav, monu, eg
+    partizip_perfekt: Option<AODeklination<'a>>, // laudatus, laudata, laudatum
+    athematisch: bool,                           // fero, ferre und Komposita: fers, fert
+}
+
+impl<'a> Verb<'a> {
+    pub fn parse(eintrag: &WörterbuchEintrag<'a>) -> Result<Self, ParseFehler> {
+        let mut ablehnungen = Vec::with_capacity(Konjugationsklasse::ALLE.len());
+        for klasse in Konjugationsklasse::ALLE {
+            match Self::parse_klasse(eintrag, klasse) {
+                Ok(verb) => return Ok(verb),
+                Err(grund) => ablehnungen.push(Ablehnung::new(klasse.get_name(), grund)),
+            }
+        }
+        Err(ParseFehler::KeineKonjugation(ablehnungen))
+    }
+
+    fn parse_klasse(
+        eintrag: &WörterbuchEintrag<'a>,
+        klasse: Konjugationsklasse,
+    ) -> Result<Self, Ablehnungsgrund> {
+        let (erste_form_endung, infinitiv_endung) = klasse.get_muster();
+        let stamm = eintrag
+            .erste_form
+            .strip_suffix(erste_form_endung)
+            .ok_or(Ablehnungsgrund::ErsteFormPasstNicht)?;
+        if stamm.is_empty() {
+            return Err(Ablehnungsgrund::ErsteFormPasstNicht);
+        }
+        let athematisch = klasse == Konjugationsklasse::KonsonantischeKonjugation
+            && stamm.ends_with("fer")
+            && test_form(eintrag.infinitiv, stamm, "re");
+        if !athematisch && !test_form(eintrag.infinitiv, stamm, infinitiv_endung) {
+            return Err(Ablehnungsgrund::InfinitivPasstNicht);
+        }
+        let perfektstamm = match eintrag.perfekt {
+            Some(perfekt) if test_stammform(perfekt, klasse, stamm, Stammform::Perfekt) => {
+                perfekt.strip_suffix('i')
+            }
+            Some(_) => return Err(Ablehnungsgrund::PerfektPasstNicht),
+            None => None,
+        };
+        let partizip_perfekt = match eintrag.supinum {
+            Some(supinum) if test_stammform(supinum, klasse, stamm, Stammform::Supinum) => {
+                supinum.strip_suffix("um").map(AODeklination::from_stamm)
+            }
+            Some(_) => return Err(Ablehnungsgrund::SupinumPasstNicht),
+            None => None,
+        };
+
+        Ok(Self {
+            klassifikation: Klassifikation { klasse, stamm },
+            perfektstamm,
+            partizip_perfekt,
+            athematisch,
+        })
+    }
+
+    pub fn get_klassifikation(&self) -> Klassifikation<'a> {
+        self.klassifikation
+    }
+
+    // Das Genus gibt nur bei den zusammengesetzten Formen des Passivs den Ausschlag:
+    // laudatus, laudata, laudatum est. Formen, die es nicht gibt, wie der Imperativ der ersten
+    // Person, ergeben None
+    pub fn konjugieren(
+        &self,
+        person: Person,
+        numerus: Numerus,
+        tempus: Tempus,
+        modus: Modus,
+        genus_verbi: GenusVerbi,
+        genus: Genus,
+    ) -> Option<String> {
+        let klasse = self.klassifikation.klasse;
+        let (stamm, endung) = match (tempus, modus, genus_verbi) {
+            (_, Modus::Imperativ, _) => (
+                self.klassifikation.stamm,
+                imperativ::get_endung(
+                    self.klassifikation,
+                    self.athematisch,
+                    tempus,
+                    genus_verbi,
+                    person,
+                    numerus,
+                )?,
+            ),
+            (Tempus::Präsens | Tempus::Imperfekt | Tempus::Futur, _, _) => (
+                self.klassifikation.stamm,
+                praesens::get_endung(
+                    klasse,
+                    self.athematisch,
+                    tempus,
+                    modus,
+                    genus_verbi,
+                    person,
+                    numerus,
+                )?,
+            ),
+            (Tempus::Perfekt | Tempus::Plusquamperfekt | Tempus::FuturII, _, GenusVerbi::Aktiv) => {
+                (
+                    self.perfektstamm?,
+                    perfekt::get_endung(tempus, modus, person, numerus)?,
+                )
+            }
+            (
+                Tempus::Perfekt | Tempus::Plusquamperfekt | Tempus::FuturII,
+                _,
+                GenusVerbi::Passiv,
+            ) => {
+                return self.zusammengesetzt(person, numerus, tempus, modus, genus);
+            }
+        };
+
+        let mut form = String::with_capacity(stamm.len() + endung.len());
+        form.push_str(stamm);
+        form.push_str(endung);
+        Some(form)
+    }
+
+    // Partizip Perfekt Passiv mit sum, eram, ero für Perfekt, Plusquamperfekt und Futur II, im
+    // Konjunktiv mit sim und essem
+    fn zusammengesetzt(
+        &self,
+        person: Person,
+        numerus: Numerus,
+        tempus: Tempus,
+        modus: Modus,
+        genus: Genus,
+    ) -> Option<String> {
+        let partizip =
+            self.partizip_perfekt
+                .as_ref()?
+                .deklinieren(genus, numerus, Kasus::Nominativ)?;
+        let hilfsverb_tempus = match tempus {
+            Tempus::Perfekt => Tempus::Präsens,
+            Tempus::Plusquamperfekt => Tempus::Imperfekt,
+            Tempus::FuturII => Tempus::Futur,
+            Tempus::Präsens | Tempus::Imperfekt | Tempus::Futur => return None,
+        };
+        let hilfsverb = esse::get_form(hilfsverb_tempus, modus, person, numerus)?;
+
+        Some([partizip.as_str(), hilfsverb].join(" "))
+    }
+
+    // Nebenformen wie laudavere oder laudare folgen auf die Form von konjugieren
+    pub fn varianten(
+        &self,
+        person: Person,
+        numerus: Numerus,
+        tempus: Tempus,
+        modus: Modus,
+        genus_verbi: GenusVerbi,
+        genus: Genus,
+    ) -> Vec<String> {
+        let Some(form) = self.konjugieren(person, numerus, tempus, modus, genus_verbi, genus)
+        else {
+            return Vec::new();
+        };
+
+        let mut varianten = vec![form];
+        match genus_verbi {
+            GenusVerbi::Aktiv => {
+                if let Some(perfektstamm) = self.perfektstamm {
+                    for endung in perfekt::get_varianten(tempus, modus, person, numerus) {
+                        varianten.push([perfektstamm, endung].concat());
+                    }
+                }
+            }
+            // laudare neben laudaris, laudabere neben laudaberis, laudere neben lauderis
+            GenusVerbi::Passiv => {
+                if let (Person::Zweite, Numerus::Singular) = (person, numerus) {
+                    if let Some(stamm) = varianten[0].strip_suffix("ris") {
+                        let variante = [stamm, "re"].concat();
+                        varianten.push(variante);
+                    }
+                }
+            }
+        }
+        varianten
+    }
+}
@@ -0,0 +1,46 @@
+use crate::grammatik::{Modus, Numerus, Person, Tempus};
+
+use super::index;
+
+// Endungen, die an den Perfektstamm treten, für alle Konjugationsklassen gleich
+type Endungen = [&'static str; 6];
+
+const PERFEKT: Endungen = ["i", "isti", "it", "imus", "istis", "erunt"];
+const PLUSQUAMPERFEKT: Endungen = ["eram", "eras", "erat", "eramus", "eratis", "erant"];
+const FUTUR_II: Endungen = ["ero", "eris", "erit", "erimus", "eritis", "erint"];
+
+const KONJUNKTIV_PERFEKT: Endungen = ["erim", "eris", "erit", "erimus", "eritis", "erint"];
+const KONJUNKTIV_PLUSQUAMPERFEKT: Endungen =
+    ["issem", "isses", "isset", "issemus", "issetis", "issent"];
+
+pub fn get_endung(
+    tempus: Tempus,
+    modus: Modus,
+    person: Person,
+    numerus: Numerus,
+) -> Option<&'static str> {
+    let endungen = match (modus, tempus) {
+        (Modus::Indikativ, Tempus::Perfekt) => &PERFEKT,
+        (Modus::Indikativ, Tempus::Plusquamperfekt) => &PLUSQUAMPERFEKT,
+        (Modus::Indikativ, Tempus::FuturII) => &FUTUR_II,
+        (Modus::Konjunktiv, Tempus::Perfekt) => &KONJUNKTIV_PERFEKT,
+        (Modus::Konjunktiv, Tempus::Plusquamperfekt) => &KONJUNKTIV_PLUSQUAMPERFEKT,
+        (Modus::Konjunktiv, Tempus::FuturII)
+        | (Modus::Imperativ, _)
+        | (_, Tempus::Präsens | Tempus::Imperfekt | Tempus::Futur) => return None,
+    };
+    Some(endungen[index(person, numerus)])
+}
+
+// laudavere neben laudaverunt
+pub fn get_varianten(
+    tempus: Tempus,
+    modus: Modus,
+    person: Person,
+    numerus: Numerus,
+) -> &'static [&'static str] {
+    match (tempus, modus, person, numerus) {
+        (Tempus::Perfekt, Modus::Indikativ, Person::Dritte, Numerus::Plural) => &["ere"],
+        _ => &[],
+    }
+}
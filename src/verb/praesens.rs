@@ -0,0 +1,208 @@
+use crate::grammatik::{GenusVerbi, Modus, Numerus, Person, Tempus};
+
+use super::{index, Konjugationsklasse};
+
+// Endungen samt Kennvokal und Tempuszeichen, in der Reihenfolge o, s, t, mus, tis, nt
+type Endungen = [&'static str; 6];
+
+const PRÄSENS_A: Endungen = ["o", "as", "at", "amus", "atis", "ant"];
+const PRÄSENS_E: Endungen = ["eo", "es", "et", "emus", "etis", "ent"];
+const PRÄSENS_KONSONANTISCH: Endungen = ["o", "is", "it", "imus", "itis", "unt"];
+const PRÄSENS_I: Endungen = ["io", "is", "it", "imus", "itis", "iunt"];
+
+const IMPERFEKT_A: Endungen = ["abam", "abas", "abat", "abamus", "abatis", "abant"];
+const IMPERFEKT_E: Endungen = ["ebam", "ebas", "ebat", "ebamus", "ebatis", "ebant"];
+const IMPERFEKT_I: Endungen = ["iebam", "iebas", "iebat", "iebamus", "iebatis", "iebant"];
+
+const FUTUR_A: Endungen = ["abo", "abis", "abit", "abimus", "abitis", "abunt"];
+const FUTUR_E: Endungen = ["ebo", "ebis", "ebit", "ebimus", "ebitis", "ebunt"];
+const FUTUR_KONSONANTISCH: Endungen = ["am", "es", "et", "emus", "etis", "ent"];
+const FUTUR_I: Endungen = ["iam", "ies", "iet", "iemus", "ietis", "ient"];
+
+const PRÄSENS_PASSIV_A: Endungen = ["or", "aris", "atur", "amur", "amini", "antur"];
+const PRÄSENS_PASSIV_E: Endungen = ["eor", "eris", "etur", "emur", "emini", "entur"];
+const PRÄSENS_PASSIV_KONSONANTISCH: Endungen = ["or", "eris", "itur", "imur", "imini", "untur"];
+const PRÄSENS_PASSIV_KURZVOKALISCH_I: Endungen = ["ior", "eris", "itur", "imur", "imini", "iuntur"];
+const PRÄSENS_PASSIV_I: Endungen = ["ior", "iris", "itur", "imur", "imini", "iuntur"];
+
+const IMPERFEKT_PASSIV_A: Endungen = ["abar", "abaris", "abatur", "abamur", "abamini", "abantur"];
+const IMPERFEKT_PASSIV_E: Endungen = ["ebar", "ebaris", "ebatur", "ebamur", "ebamini", "ebantur"];
+const IMPERFEKT_PASSIV_I: Endungen = [
+    "iebar", "iebaris", "iebatur", "iebamur", "iebamini", "iebantur",
+];
+
+const FUTUR_PASSIV_A: Endungen = ["abor", "aberis", "abitur", "abimur", "abimini", "abuntur"];
+const FUTUR_PASSIV_E: Endungen = ["ebor", "eberis", "ebitur", "ebimur", "ebimini", "ebuntur"];
+const FUTUR_PASSIV_KONSONANTISCH: Endungen = ["ar", "eris", "etur", "emur", "emini", "entur"];
+const FUTUR_PASSIV_I: Endungen = ["iar", "ieris", "ietur", "iemur", "iemini", "ientur"];
+
+const KONJUNKTIV_PRÄSENS_A: Endungen = ["em", "es", "et", "emus", "etis", "ent"];
+const KONJUNKTIV_PRÄSENS_E: Endungen = ["eam", "eas", "eat", "eamus", "eatis", "eant"];
+const KONJUNKTIV_PRÄSENS_KONSONANTISCH: Endungen = ["am", "as", "at", "amus", "atis", "ant"];
+const KONJUNKTIV_PRÄSENS_I: Endungen = ["iam", "ias", "iat", "iamus", "iatis", "iant"];
+
+const KONJUNKTIV_IMPERFEKT_A: Endungen = ["arem", "ares", "aret", "aremus", "aretis", "arent"];
+const KONJUNKTIV_IMPERFEKT_E: Endungen = ["erem", "eres", "eret", "eremus", "eretis", "erent"];
+const KONJUNKTIV_IMPERFEKT_I: Endungen = ["irem", "ires", "iret", "iremus", "iretis", "irent"];
+
+const KONJUNKTIV_PRÄSENS_PASSIV_A: Endungen = ["er", "eris", "etur", "emur", "emini", "entur"];
+const KONJUNKTIV_PRÄSENS_PASSIV_E: Endungen =
+    ["ear", "earis", "eatur", "eamur", "eamini", "eantur"];
+const KONJUNKTIV_PRÄSENS_PASSIV_KONSONANTISCH: Endungen =
+    ["ar", "aris", "atur", "amur", "amini", "antur"];
+const KONJUNKTIV_PRÄSENS_PASSIV_I: Endungen =
+    ["iar", "iaris", "iatur", "iamur", "iamini", "iantur"];
+
+const KONJUNKTIV_IMPERFEKT_PASSIV_A: Endungen =
+    ["arer", "areris", "aretur", "aremur", "aremini", "arentur"];
+const KONJUNKTIV_IMPERFEKT_PASSIV_E: Endungen =
+    ["erer", "ereris", "eretur", "eremur", "eremini", "erentur"];
+const KONJUNKTIV_IMPERFEKT_PASSIV_I: Endungen =
+    ["irer", "ireris", "iretur", "iremur", "iremini", "irentur"];
+
+const KONJUNKTIV_IMPERFEKT_ATHEMATISCH: Endungen = ["rem", "res", "ret", "remus", "retis", "rent"];
+const KONJUNKTIV_IMPERFEKT_PASSIV_ATHEMATISCH: Endungen =
+    ["rer", "reris", "retur", "remur", "remini", "rentur"];
+
+// fero, ferre: fers, fert, fertis, ferris, fertur, ferrem; die übrigen Formen wie in der
+// konsonantischen Konjugation
+fn get_athematische_endung(
+    tempus: Tempus,
+    modus: Modus,
+    genus_verbi: GenusVerbi,
+    person: Person,
+    numerus: Numerus,
+) -> Option<&'static str> {
+    Some(match (tempus, modus, genus_verbi, person, numerus) {
+        (
+            Tempus::Präsens,
+            Modus::Indikativ,
+            GenusVerbi::Aktiv,
+            Person::Zweite,
+            Numerus::Singular,
+        ) => "s",
+        (
+            Tempus::Präsens,
+            Modus::Indikativ,
+            GenusVerbi::Aktiv,
+            Person::Dritte,
+            Numerus::Singular,
+        ) => "t",
+        (Tempus::Präsens, Modus::Indikativ, GenusVerbi::Aktiv, Person::Zweite, Numerus::Plural) => {
+            "tis"
+        }
+        (
+            Tempus::Präsens,
+            Modus::Indikativ,
+            GenusVerbi::Passiv,
+            Person::Zweite,
+            Numerus::Singular,
+        ) => "ris",
+        (
+            Tempus::Präsens,
+            Modus::Indikativ,
+            GenusVerbi::Passiv,
+            Person::Dritte,
+            Numerus::Singular,
+        ) => "tur",
+        (Tempus::Imperfekt, Modus::Konjunktiv, GenusVerbi::Aktiv, _, _) => {
+            KONJUNKTIV_IMPERFEKT_ATHEMATISCH[index(person, numerus)]
+        }
+        (Tempus::Imperfekt, Modus::Konjunktiv, GenusVerbi::Passiv, _, _) => {
+            KONJUNKTIV_IMPERFEKT_PASSIV_ATHEMATISCH[index(person, numerus)]
+        }
+        _ => return None,
+    })
+}
+
+pub fn get_endung(
+    klasse: Konjugationsklasse,
+    athematisch: bool,
+    tempus: Tempus,
+    modus: Modus,
+    genus_verbi: GenusVerbi,
+    person: Person,
+    numerus: Numerus,
+) -> Option<&'static str> {
+    use Konjugationsklasse as K;
+
+    if athematisch {
+        if let Some(endung) = get_athematische_endung(tempus, modus, genus_verbi, person, numerus) {
+            return Some(endung);
+        }
+    }
+
+    let endungen = match (modus, genus_verbi) {
+        (Modus::Indikativ, GenusVerbi::Aktiv) => match (tempus, klasse) {
+            (Tempus::Präsens, K::AKonjugation) => &PRÄSENS_A,
+            (Tempus::Präsens, K::EKonjugation) => &PRÄSENS_E,
+            (Tempus::Präsens, K::KonsonantischeKonjugation) => &PRÄSENS_KONSONANTISCH,
+            (Tempus::Präsens, K::KurzvokalischeIKonjugation | K::IKonjugation) => &PRÄSENS_I,
+            (Tempus::Imperfekt, K::AKonjugation) => &IMPERFEKT_A,
+            (Tempus::Imperfekt, K::EKonjugation | K::KonsonantischeKonjugation) => &IMPERFEKT_E,
+            (Tempus::Imperfekt, K::KurzvokalischeIKonjugation | K::IKonjugation) => &IMPERFEKT_I,
+            (Tempus::Futur, K::AKonjugation) => &FUTUR_A,
+            (Tempus::Futur, K::EKonjugation) => &FUTUR_E,
+            (Tempus::Futur, K::KonsonantischeKonjugation) => &FUTUR_KONSONANTISCH,
+            (Tempus::Futur, K::KurzvokalischeIKonjugation | K::IKonjugation) => &FUTUR_I,
+            (Tempus::Perfekt | Tempus::Plusquamperfekt | Tempus::FuturII, _) => return None,
+        },
+        (Modus::Indikativ, GenusVerbi::Passiv) => match (tempus, klasse) {
+            (Tempus::Präsens, K::AKonjugation) => &PRÄSENS_PASSIV_A,
+            (Tempus::Präsens, K::EKonjugation) => &PRÄSENS_PASSIV_E,
+            (Tempus::Präsens, K::KonsonantischeKonjugation) => &PRÄSENS_PASSIV_KONSONANTISCH,
+            (Tempus::Präsens, K::KurzvokalischeIKonjugation) => &PRÄSENS_PASSIV_KURZVOKALISCH_I,
+            (Tempus::Präsens, K::IKonjugation) => &PRÄSENS_PASSIV_I,
+            (Tempus::Imperfekt, K::AKonjugation) => &IMPERFEKT_PASSIV_A,
+            (Tempus::Imperfekt, K::EKonjugation | K::KonsonantischeKonjugation) => {
+                &IMPERFEKT_PASSIV_E
+            }
+            (Tempus::Imperfekt, K::KurzvokalischeIKonjugation | K::IKonjugation) => {
+                &IMPERFEKT_PASSIV_I
+            }
+            (Tempus::Futur, K::AKonjugation) => &FUTUR_PASSIV_A,
+            (Tempus::Futur, K::EKonjugation) => &FUTUR_PASSIV_E,
+            (Tempus::Futur, K::KonsonantischeKonjugation) => &FUTUR_PASSIV_KONSONANTISCH,
+            (Tempus::Futur, K::KurzvokalischeIKonjugation | K::IKonjugation) => &FUTUR_PASSIV_I,
+            (Tempus::Perfekt | Tempus::Plusquamperfekt | Tempus::FuturII, _) => return None,
+        },
+        (Modus::Konjunktiv, GenusVerbi::Aktiv) => match (tempus, klasse) {
+            (Tempus::Präsens, K::AKonjugation) => &KONJUNKTIV_PRÄSENS_A,
+            (Tempus::Präsens, K::EKonjugation) => &KONJUNKTIV_PRÄSENS_E,
+            (Tempus::Präsens, K::KonsonantischeKonjugation) => &KONJUNKTIV_PRÄSENS_KONSONANTISCH,
+            (Tempus::Präsens, K::KurzvokalischeIKonjugation | K::IKonjugation) => {
+                &KONJUNKTIV_PRÄSENS_I
+            }
+            (Tempus::Imperfekt, K::AKonjugation) => &KONJUNKTIV_IMPERFEKT_A,
+            (
+                Tempus::Imperfekt,
+                K::EKonjugation | K::KonsonantischeKonjugation | K::KurzvokalischeIKonjugation,
+            ) => &KONJUNKTIV_IMPERFEKT_E,
+            (Tempus::Imperfekt, K::IKonjugation) => &KONJUNKTIV_IMPERFEKT_I,
+            (Tempus::Futur | Tempus::Perfekt | Tempus::Plusquamperfekt | Tempus::FuturII, _) => {
+                return None
+            }
+        },
+        (Modus::Konjunktiv, GenusVerbi::Passiv) => match (tempus, klasse) {
+            (Tempus::Präsens, K::AKonjugation) => &KONJUNKTIV_PRÄSENS_PASSIV_A,
+            (Tempus::Präsens, K::EKonjugation) => &KONJUNKTIV_PRÄSENS_PASSIV_E,
+            (Tempus::Präsens, K::KonsonantischeKonjugation) => {
+                &KONJUNKTIV_PRÄSENS_PASSIV_KONSONANTISCH
+            }
+            (Tempus::Präsens, K::KurzvokalischeIKonjugation | K::IKonjugation) => {
+                &KONJUNKTIV_PRÄSENS_PASSIV_I
+            }
+            (Tempus::Imperfekt, K::AKonjugation) => &KONJUNKTIV_IMPERFEKT_PASSIV_A,
+            (
+                Tempus::Imperfekt,
+                K::EKonjugation | K::KonsonantischeKonjugation | K::KurzvokalischeIKonjugation,
+            ) => &KONJUNKTIV_IMPERFEKT_PASSIV_E,
+            (Tempus::Imperfekt, K::IKonjugation) => &KONJUNKTIV_IMPERFEKT_PASSIV_I,
+            (Tempus::Futur | Tempus::Perfekt | Tempus::Plusquamperfekt | Tempus::FuturII, _) => {
+                return None
+            }
+        },
+        (Modus::Imperativ, _) => return None,
+    };
+    Some(endungen[index(person, numerus)])
+}
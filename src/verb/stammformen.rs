@@ -0,0 +1,332 @@
+use crate::grammatik::{ist_vokal, test_form};
+
+use super::Konjugationsklasse;
+
+// Präsens-, Perfekt- und Supinstamm der Verben, deren Stammformen sich nicht aus dem Präsensstamm
+// herleiten lassen. Komposita werden über das Ende des Präsensstamms gefunden, auch wenn sich das
+// Präfix angleicht: perfero, pertuli, perlatum; affero, attuli, allatum
+type Stammformen = (&'static str, &'static str, Option<&'static str>);
+
+static UNREGELMÄSSIG: [Stammformen; 16] = [
+    ("fer", "tul", Some("lat")),
+    ("toll", "sustul", Some("sublat")),
+    ("pon", "posu", Some("posit")),
+    ("viv", "vix", Some("vict")),
+    ("quaer", "quaesiv", Some("quaesit")),
+    ("iub", "iuss", Some("iuss")),
+    ("cern", "crev", Some("cret")),
+    ("sper", "sprev", Some("spret")),
+    ("sin", "siv", Some("sit")),
+    ("pell", "pepul", Some("puls")),
+    ("fall", "fefell", Some("fals")),
+    ("col", "colu", Some("cult")),
+    ("disc", "didic", None),
+    ("cog", "coeg", Some("coact")),
+    ("reper", "repper", Some("repert")),
+    ("cognosc", "cognov", Some("cognit")),
+];
+
+#[derive(Clone, Copy)]
+pub enum Stammform {
+    Perfekt, // laudavi
+    Supinum, // laudatum
+}
+
+impl Stammform {
+    fn get_endung(self) -> &'static str {
+        match self {
+            Self::Perfekt => "i",
+            Self::Supinum => "um",
+        }
+    }
+
+    // Erweiterungen des ganzen Präsensstamms: laud-av-i, mon-u-i, impl-ev-i, pet-iv-i, aud-it-um
+    fn get_erweiterungen(self, klasse: Konjugationsklasse) -> &'static [&'static str] {
+        match (self, klasse) {
+            (Self::Perfekt, Konjugationsklasse::AKonjugation) => &["av", "u"],
+            (Self::Perfekt, Konjugationsklasse::EKonjugation) => &["u", "ev"],
+            (Self::Perfekt, Konjugationsklasse::IKonjugation) => &["iv", "u"],
+            (Self::Perfekt, _) => &["u", "iv"],
+            (Self::Supinum, Konjugationsklasse::AKonjugation) => &["at", "it"],
+            (Self::Supinum, Konjugationsklasse::EKonjugation) => &["it", "et"],
+            (Self::Supinum, _) => &["it"],
+        }
+    }
+
+    fn ableiten(self, klasse: Konjugationsklasse, präsensstamm: &str) -> Vec<String> {
+        match self {
+            Self::Perfekt => perfektstämme(klasse, präsensstamm),
+            Self::Supinum => supinstämme(präsensstamm),
+        }
+    }
+
+    fn get_unregelmäßig(self, &(_, perfekt, supinum): &Stammformen) -> Option<&'static str> {
+        match self {
+            Self::Perfekt => Some(perfekt),
+            Self::Supinum => supinum,
+        }
+    }
+}
+
+// Das u in qu und ngu ist kein Vokal: relinqu, exstingu
+fn ist_stammvokal(stamm: &str, index: usize, buchstabe: char) -> bool {
+    ist_vokal(buchstabe)
+        && !(buchstabe == 'u' && (stamm[..index].ends_with('q') || stamm[..index].ends_with("ng")))
+}
+
+// Anfang, letzter Vokal und Auslaut des Präsensstamms: collig -> coll, i, g; constitu -> constit,
+// u, ""; None bei do und sto
+fn zerlegen(stamm: &str) -> Option<(&str, &str, &str)> {
+    let mut vokal: Option<(usize, usize)> = None;
+    for (index, buchstabe) in stamm.char_indices() {
+        if !ist_stammvokal(stamm, index, buchstabe) {
+            continue;
+        }
+        let ende = index + buchstabe.len_utf8();
+        vokal = match vokal {
+            Some((anfang, vorher)) if vorher == index => Some((anfang, ende)),
+            _ => Some((index, ende)),
+        };
+    }
+    let (anfang, ende) = vokal?;
+    Some((&stamm[..anfang], &stamm[anfang..ende], &stamm[ende..]))
+}
+
+// Ablaut des letzten Vokals: cap-, cep-; collig-, colleg-; beim reduplizierten Perfekt auch
+// can-, cecin-; caed-, cecid-
+fn vokale(vokal: &str, reduplikation: bool) -> Vec<String> {
+    let mut vokale = vec![String::from(vokal)];
+    if let Some(rest) = vokal.strip_suffix(['a', 'i']) {
+        vokale.push([rest, "e"].concat());
+    }
+    if reduplikation {
+        if let Some(rest) = vokal.strip_suffix("ae").or_else(|| vokal.strip_suffix('a')) {
+            vokale.push([rest, "i"].concat());
+        }
+    }
+    vokale
+}
+
+// Der Nasal vor dem Auslaut fällt oft aus (vinc-, vic-, vict-; frang-, freg-, fract-),
+// Doppelkonsonanten werden vereinfacht (mitt-, mis-; curr-, curs-)
+fn auslaute(auslaut: &str) -> Vec<&str> {
+    let mut auslaute = vec![auslaut];
+    if auslaut.len() > 1 && auslaut.starts_with(['n', 'm']) {
+        auslaute.push(&auslaut[1..]);
+    }
+    let mut buchstaben = auslaut.chars().rev();
+    if let (Some(letzter), Some(vorletzter)) = (buchstaben.next(), buchstaben.next()) {
+        if letzter == vorletzter {
+            auslaute.push(&auslaut[..auslaut.len() - letzter.len_utf8()]);
+        }
+    }
+    auslaute
+}
+
+// Der Auslaut im s-, x- und v-Perfekt: dic-, dix-; scrib-, scrips-; claud-, claus-; merg-,
+// mers-; pasc-, pav-; cred-, credid-
+fn perfekt_auslaute(auslaut: &str) -> Vec<String> {
+    let mut auslaute = vec![String::from(auslaut)];
+    if let Some(rest) = auslaut.strip_suffix("sc") {
+        auslaute.push([rest, "v"].concat());
+    } else if let Some(rest) = ["qu", "gu", "c", "g", "h"]
+        .iter()
+        .find_map(|&guttural| auslaut.strip_suffix(guttural))
+    {
+        if rest.ends_with(['r', 'l']) {
+            auslaute.push([rest, "s"].concat());
+        } else {
+            auslaute.push([rest, "x"].concat());
+        }
+    } else if let Some(rest) = auslaut.strip_suffix(['b', 'p']) {
+        auslaute.push([rest, "ps"].concat());
+    } else if auslaut.ends_with('m') {
+        auslaute.push([auslaut, "ps"].concat());
+    } else if auslaut.ends_with('n') {
+        auslaute.push([auslaut, "s"].concat());
+    } else if let Some(rest) = auslaut.strip_suffix(['d', 't', 'r']) {
+        auslaute.push([rest, "s"].concat());
+        auslaute.push([rest, "ss"].concat());
+        if auslaut.ends_with('d') {
+            auslaute.push([auslaut, "id"].concat());
+        }
+    }
+    auslaute
+}
+
+// Der Auslaut im Supinstamm: constitu-, constitut-; reg-, rect-; scrib-, script-; sum-, sumpt-;
+// claud-, claus-; ostend-, ostent-; ger-, gest-; mov-, mot-; lav-, laut-; pasc-, past-
+fn supinum_auslaute(auslaut: &str) -> Vec<String> {
+    let mut auslaute = Vec::new();
+    if auslaut.is_empty() {
+        auslaute.push(String::from("t"));
+    } else if let Some(rest) = auslaut.strip_suffix("sc") {
+        auslaute.push([rest, "st"].concat());
+        auslaute.push([rest, "t"].concat());
+    } else if let Some(rest) = ["qu", "gu", "c", "g", "h"]
+        .iter()
+        .find_map(|&guttural| auslaut.strip_suffix(guttural))
+    {
+        auslaute.push([rest, "ct"].concat());
+        if rest.ends_with(['r', 'l']) {
+            auslaute.push([rest, "s"].concat());
+        }
+    } else if let Some(rest) = auslaut.strip_suffix(['b', 'p']) {
+        auslaute.push([rest, "pt"].concat());
+    } else if auslaut.ends_with('m') {
+        auslaute.push([auslaut, "pt"].concat());
+    } else if auslaut.ends_with(['n', 'l']) {
+        auslaute.push([auslaut, "t"].concat());
+        auslaute.push([auslaut, "s"].concat());
+    } else if let Some(rest) = auslaut.strip_suffix('r') {
+        auslaute.push([auslaut, "t"].concat());
+        auslaute.push([auslaut, "s"].concat());
+        auslaute.push([rest, "st"].concat());
+    } else if let Some(rest) = auslaut.strip_suffix(['d', 't']) {
+        auslaute.push([rest, "s"].concat());
+        auslaute.push([rest, "ss"].concat());
+        auslaute.push([rest, "t"].concat());
+    } else if let Some(rest) = auslaut.strip_suffix('v') {
+        auslaute.push([rest, "t"].concat());
+        auslaute.push([rest, "ut"].concat());
+    } else if auslaut.ends_with('s') {
+        auslaute.push(String::from(auslaut));
+    }
+    auslaute
+}
+
+// do, dedi; sto, steti; cado, cecidi; curro, cucurri; tango, tetigi; spondeo, spopondi
+fn reduplikationen(präsensstamm: &str) -> Vec<String> {
+    let Some((anfang, vokal, auslaut)) = zerlegen(präsensstamm) else {
+        let mut stamm = [präsensstamm, "e"].concat();
+        stamm.extend(präsensstamm.chars().last());
+        return vec![stamm];
+    };
+    let Some(anlaut) = anfang
+        .get(..1)
+        .filter(|anlaut| !anlaut.starts_with(ist_vokal))
+    else {
+        return Vec::new();
+    };
+    let doppelanlaut = ["sp", "st", "sc"]
+        .iter()
+        .any(|&doppel| präsensstamm.starts_with(doppel));
+
+    let mut stämme = Vec::new();
+    for neuer_vokal in vokale(vokal, true) {
+        for neuer_auslaut in auslaute(auslaut) {
+            let stamm = [anfang, &neuer_vokal, neuer_auslaut].concat();
+            for silbenvokal in ["e", &vokal[..1]] {
+                stämme.push(if doppelanlaut {
+                    [&präsensstamm[..2], silbenvokal, &stamm[1..]].concat()
+                } else {
+                    [anlaut, silbenvokal, &stamm].concat()
+                });
+            }
+        }
+    }
+    stämme
+}
+
+// Die a-Konjugation kennt außer -avi nur Perfekte ohne Änderung des Auslauts: iuvo, iuvi
+fn perfektstämme(klasse: Konjugationsklasse, präsensstamm: &str) -> Vec<String> {
+    let mut stämme = reduplikationen(präsensstamm);
+    let Some((anfang, vokal, auslaut)) = zerlegen(präsensstamm) else {
+        return stämme;
+    };
+    for neuer_vokal in vokale(vokal, false) {
+        for neuer_auslaut in auslaute(auslaut) {
+            if klasse == Konjugationsklasse::AKonjugation {
+                stämme.push([anfang, &neuer_vokal, neuer_auslaut].concat());
+                continue;
+            }
+            for perfekt_auslaut in perfekt_auslaute(neuer_auslaut) {
+                stämme.push([anfang, &neuer_vokal, &perfekt_auslaut].concat());
+            }
+        }
+    }
+    stämme
+}
+
+fn supinstämme(präsensstamm: &str) -> Vec<String> {
+    let Some((anfang, vokal, auslaut)) = zerlegen(präsensstamm) else {
+        return Vec::new();
+    };
+    let mut stämme = Vec::new();
+    for neuer_vokal in vokale(vokal, false) {
+        for neuer_auslaut in auslaute(auslaut) {
+            for supinum_auslaut in supinum_auslaute(neuer_auslaut) {
+                stämme.push([anfang, &neuer_vokal, &supinum_auslaut].concat());
+            }
+        }
+    }
+    stämme
+}
+
+// Konsonanten, auf die Präfixe vor der Angleichung enden: ad, ob, sub, in, com, dis, ex
+const PRÄFIX_KONSONANTEN: [char; 6] = ['b', 'd', 'm', 'n', 's', 'x'];
+
+// Präfix ohne seinen letzten Konsonanten, wenn dieser angeglichen oder ursprünglich ist:
+// af-fer -> a, at-tul -> a, dis-tul -> di
+fn präfix_ohne_konsonant<'a>(präfix: &'a str, anlaut: &str) -> &'a str {
+    match präfix.strip_suffix(|konsonant| {
+        anlaut.starts_with(konsonant) || PRÄFIX_KONSONANTEN.contains(&konsonant)
+    }) {
+        Some(rest) if !rest.is_empty() => rest,
+        _ => präfix,
+    }
+}
+
+// Komposita behalten das Präfix des Präsens, auch wenn es sich angleicht: af-fero, at-tuli,
+// al-latum; re-fero, ret-tuli; dif-fero, dis-tuli, di-latum
+fn test_präfix(
+    präsens_präfix: &str, präsens_anlaut: &str, präfix: &str, anlaut: &str
+) -> bool {
+    präsens_präfix == präfix
+        || (!präsens_präfix.is_empty()
+            && !präfix.is_empty()
+            && präfix_ohne_konsonant(präsens_präfix, präsens_anlaut)
+                == präfix_ohne_konsonant(präfix, anlaut))
+}
+
+// Perfekt und Supinum müssen sich aus dem Präsensstamm herleiten lassen: durch die Erweiterung
+// der Klasse (laudavi, laudatum), Ablaut (capio, cepi), s- und x-Perfekt (dico, dixi, dictum),
+// Reduplikation (cado, cecidi) oder die Liste der unregelmäßigen Stammformen (fero, tuli, latum)
+pub fn test_stammform(
+    form: &str,
+    klasse: Konjugationsklasse,
+    präsensstamm: &str,
+    stammform: Stammform,
+) -> bool {
+    let Some(stamm) = form.strip_suffix(stammform.get_endung()) else {
+        return false;
+    };
+    if stamm.is_empty() {
+        return false;
+    }
+    if stammform
+        .get_erweiterungen(klasse)
+        .iter()
+        .any(|erweiterung| test_form(stamm, präsensstamm, erweiterung))
+    {
+        return true;
+    }
+    if stammform
+        .ableiten(klasse, präsensstamm)
+        .iter()
+        .any(|abgeleitet| abgeleitet == stamm)
+    {
+        return true;
+    }
+    UNREGELMÄSSIG.iter().any(|stammformen| {
+        let Some(präsens_präfix) = präsensstamm.strip_suffix(stammformen.0) else {
+            return false;
+        };
+        let Some(unregelmäßig) = stammform.get_unregelmäßig(stammformen) else {
+            return false;
+        };
+        stamm
+            .strip_suffix(unregelmäßig)
+            .is_some_and(|präfix| test_präfix(präsens_präfix, stammformen.0, präfix, unregelmäßig))
+    })
+}
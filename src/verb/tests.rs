@@ -0,0 +1,723 @@
+use super::{Ablehnungsgrund, Konjugationsklasse, ParseFehler, Verb, WörterbuchEintrag};
+use crate::grammatik::{
+    Genus::{Femininum as F, Maskulinum as M, Neutrum as N},
+    GenusVerbi::{Aktiv as Akt, Passiv as Pass},
+    Modus::{Imperativ as Imp, Indikativ as Ind, Konjunktiv as Konj},
+    Numerus::{Plural as Pl, Singular as Sg},
+    Person::{Dritte as P3, Erste as P1, Zweite as P2},
+    Tempus::{
+        Futur as Fut, FuturII as Fut2, Imperfekt as Impf, Perfekt as Perf,
+        Plusquamperfekt as Plusq, Präsens as Präs,
+    },
+};
+
+macro_rules! test_konjugation {
+    ($eintrag:expr; $($tempus:ident $modus:ident $genus_verbi:ident $person:ident $numerus:ident $($genus:ident)? => $form:literal),* $(,)?) => {
+        let Ok(verb) = Verb::parse(&$eintrag) else {
+            panic!("failed to parse verb: {:?}", $eintrag);
+        };
+        $({
+            #[allow(unused)]
+            let genus = M;
+            $(let genus = $genus;)?
+            let form = verb
+                .konjugieren($person, $numerus, $tempus, $modus, $genus_verbi, genus)
+                .unwrap();
+            assert_eq!(form, $form);
+        })*
+    };
+}
+
+#[test]
+fn test_a_konjugation() {
+    test_konjugation! {
+        WörterbuchEintrag::from_two("laudo", "laudare");
+
+        Präs Ind Akt P1 Sg => "laudo",
+        Präs Ind Akt P2 Sg => "laudas",
+        Präs Ind Akt P3 Sg => "laudat",
+        Präs Ind Akt P1 Pl => "laudamus",
+        Präs Ind Akt P2 Pl => "laudatis",
+        Präs Ind Akt P3 Pl => "laudant",
+
+        Impf Ind Akt P1 Sg => "laudabam",
+        Impf Ind Akt P2 Sg => "laudabas",
+        Impf Ind Akt P3 Sg => "laudabat",
+        Impf Ind Akt P1 Pl => "laudabamus",
+        Impf Ind Akt P2 Pl => "laudabatis",
+        Impf Ind Akt P3 Pl => "laudabant",
+
+        Fut Ind Akt P1 Sg => "laudabo",
+        Fut Ind Akt P2 Sg => "laudabis",
+        Fut Ind Akt P3 Sg => "laudabit",
+        Fut Ind Akt P1 Pl => "laudabimus",
+        Fut Ind Akt P2 Pl => "laudabitis",
+        Fut Ind Akt P3 Pl => "laudabunt",
+    }
+}
+
+#[test]
+fn test_e_konjugation() {
+    test_konjugation! {
+        WörterbuchEintrag::from_two("moneo", "monere");
+
+        Präs Ind Akt P1 Sg => "moneo",
+        Präs Ind Akt P2 Sg => "mones",
+        Präs Ind Akt P3 Sg => "monet",
+        Präs Ind Akt P1 Pl => "monemus",
+        Präs Ind Akt P2 Pl => "monetis",
+        Präs Ind Akt P3 Pl => "monent",
+
+        Impf Ind Akt P1 Sg => "monebam",
+        Impf Ind Akt P3 Pl => "monebant",
+
+        Fut Ind Akt P1 Sg => "monebo",
+        Fut Ind Akt P2 Sg => "monebis",
+        Fut Ind Akt P3 Pl => "monebunt",
+    }
+}
+
+#[test]
+fn test_konsonantische_konjugation() {
+    test_konjugation! {
+        WörterbuchEintrag::from_two("ago", "agere");
+
+        Präs Ind Akt P1 Sg => "ago",
+        Präs Ind Akt P2 Sg => "agis",
+        Präs Ind Akt P3 Sg => "agit",
+        Präs Ind Akt P1 Pl => "agimus",
+        Präs Ind Akt P2 Pl => "agitis",
+        Präs Ind Akt P3 Pl => "agunt",
+
+        Impf Ind Akt P1 Sg => "agebam",
+        Impf Ind Akt P2 Pl => "agebatis",
+
+        Fut Ind Akt P1 Sg => "agam",
+        Fut Ind Akt P2 Sg => "ages",
+        Fut Ind Akt P3 Sg => "aget",
+        Fut Ind Akt P1 Pl => "agemus",
+        Fut Ind Akt P2 Pl => "agetis",
+        Fut Ind Akt P3 Pl => "agent",
+    }
+}
+
+#[test]
+fn test_kurzvokalische_i_konjugation() {
+    test_konjugation! {
+        WörterbuchEintrag::from_two("capio", "capere");
+
+        Präs Ind Akt P1 Sg => "capio",
+        Präs Ind Akt P2 Sg => "capis",
+        Präs Ind Akt P3 Pl => "capiunt",
+
+        Impf Ind Akt P1 Sg => "capiebam",
+        Impf Ind Akt P3 Pl => "capiebant",
+
+        Fut Ind Akt P1 Sg => "capiam",
+        Fut Ind Akt P2 Sg => "capies",
+        Fut Ind Akt P3 Pl => "capient",
+    }
+}
+
+#[test]
+fn test_i_konjugation() {
+    test_konjugation! {
+        WörterbuchEintrag::from_two("audio", "audire");
+
+        Präs Ind Akt P1 Sg => "audio",
+        Präs Ind Akt P2 Sg => "audis",
+        Präs Ind Akt P3 Sg => "audit",
+        Präs Ind Akt P1 Pl => "audimus",
+        Präs Ind Akt P2 Pl => "auditis",
+        Präs Ind Akt P3 Pl => "audiunt",
+
+        Impf Ind Akt P1 Sg => "audiebam",
+        Impf Ind Akt P3 Pl => "audiebant",
+
+        Fut Ind Akt P1 Sg => "audiam",
+        Fut Ind Akt P2 Sg => "audies",
+        Fut Ind Akt P3 Pl => "audient",
+    }
+}
+
+#[test]
+fn test_perfekt() {
+    test_konjugation! {
+        WörterbuchEintrag::from_four("laudo", "laudavi", "laudatum", "laudare");
+
+        Perf Ind Akt P1 Sg => "laudavi",
+        Perf Ind Akt P2 Sg => "laudavisti",
+        Perf Ind Akt P3 Sg => "laudavit",
+        Perf Ind Akt P1 Pl => "laudavimus",
+        Perf Ind Akt P2 Pl => "laudavistis",
+        Perf Ind Akt P3 Pl => "laudaverunt",
+
+        Plusq Ind Akt P1 Sg => "laudaveram",
+        Plusq Ind Akt P2 Sg => "laudaveras",
+        Plusq Ind Akt P3 Sg => "laudaverat",
+        Plusq Ind Akt P1 Pl => "laudaveramus",
+        Plusq Ind Akt P2 Pl => "laudaveratis",
+        Plusq Ind Akt P3 Pl => "laudaverant",
+
+        Fut2 Ind Akt P1 Sg => "laudavero",
+        Fut2 Ind Akt P2 Sg => "laudaveris",
+        Fut2 Ind Akt P3 Sg => "laudaverit",
+        Fut2 Ind Akt P1 Pl => "laudaverimus",
+        Fut2 Ind Akt P2 Pl => "laudaveritis",
+        Fut2 Ind Akt P3 Pl => "laudaverint",
+
+        Präs Ind Akt P1 Sg => "laudo",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_four("moneo", "monui", "monitum", "monere");
+
+        Perf Ind Akt P1 Sg => "monui",
+        Plusq Ind Akt P3 Pl => "monuerant",
+        Fut2 Ind Akt P1 Sg => "monuero",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_four("ago", "egi", "actum", "agere");
+
+        Perf Ind Akt P1 Sg => "egi",
+        Perf Ind Akt P2 Sg => "egisti",
+        Plusq Ind Akt P1 Sg => "egeram",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_four("capio", "cepi", "captum", "capere");
+
+        Perf Ind Akt P3 Sg => "cepit",
+        Fut2 Ind Akt P3 Pl => "ceperint",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_four("audio", "audivi", "auditum", "audire");
+
+        Perf Ind Akt P1 Pl => "audivimus",
+        Plusq Ind Akt P2 Pl => "audiveratis",
+    }
+}
+
+#[test]
+fn test_perfekt_ohne_stammformen() {
+    let verb = Verb::parse(&WörterbuchEintrag::from_two("laudo", "laudare")).unwrap();
+    assert_eq!(verb.konjugieren(P1, Sg, Perf, Ind, Akt, M), None);
+    assert!(verb.varianten(P3, Pl, Perf, Ind, Akt, M).is_empty());
+}
+
+#[test]
+fn test_varianten() {
+    let verb = Verb::parse(&WörterbuchEintrag::from_four(
+        "laudo", "laudavi", "laudatum", "laudare",
+    ))
+    .unwrap();
+    assert_eq!(
+        verb.varianten(P3, Pl, Perf, Ind, Akt, M),
+        ["laudaverunt", "laudavere"]
+    );
+    assert_eq!(verb.varianten(P3, Sg, Perf, Ind, Akt, M), ["laudavit"]);
+    assert_eq!(verb.varianten(P3, Pl, Präs, Ind, Akt, M), ["laudant"]);
+}
+
+#[test]
+fn test_passiv() {
+    test_konjugation! {
+        WörterbuchEintrag::from_four("laudo", "laudavi", "laudatum", "laudare");
+
+        Präs Ind Pass P1 Sg => "laudor",
+        Präs Ind Pass P2 Sg => "laudaris",
+        Präs Ind Pass P3 Sg => "laudatur",
+        Präs Ind Pass P1 Pl => "laudamur",
+        Präs Ind Pass P2 Pl => "laudamini",
+        Präs Ind Pass P3 Pl => "laudantur",
+
+        Impf Ind Pass P1 Sg => "laudabar",
+        Impf Ind Pass P3 Sg => "laudabatur",
+        Impf Ind Pass P3 Pl => "laudabantur",
+
+        Fut Ind Pass P1 Sg => "laudabor",
+        Fut Ind Pass P2 Sg => "laudaberis",
+        Fut Ind Pass P3 Sg => "laudabitur",
+        Fut Ind Pass P3 Pl => "laudabuntur",
+
+        Perf Ind Pass P1 Sg => "laudatus sum",
+        Perf Ind Pass P3 Sg M => "laudatus est",
+        Perf Ind Pass P3 Sg F => "laudata est",
+        Perf Ind Pass P3 Sg N => "laudatum est",
+        Perf Ind Pass P1 Pl F => "laudatae sumus",
+        Perf Ind Pass P3 Pl N => "laudata sunt",
+
+        Plusq Ind Pass P1 Sg => "laudatus eram",
+        Plusq Ind Pass P3 Pl F => "laudatae erant",
+
+        Fut2 Ind Pass P2 Sg F => "laudata eris",
+        Fut2 Ind Pass P3 Pl => "laudati erunt",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_four("moneo", "monui", "monitum", "monere");
+
+        Präs Ind Pass P1 Sg => "moneor",
+        Präs Ind Pass P2 Sg => "moneris",
+        Impf Ind Pass P2 Pl => "monebamini",
+        Fut Ind Pass P1 Sg => "monebor",
+        Fut Ind Pass P3 Pl => "monebuntur",
+        Perf Ind Pass P3 Sg => "monitus est",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_four("ago", "egi", "actum", "agere");
+
+        Präs Ind Pass P1 Sg => "agor",
+        Präs Ind Pass P2 Sg => "ageris",
+        Präs Ind Pass P3 Sg => "agitur",
+        Präs Ind Pass P3 Pl => "aguntur",
+        Impf Ind Pass P1 Sg => "agebar",
+        Fut Ind Pass P1 Sg => "agar",
+        Fut Ind Pass P2 Sg => "ageris",
+        Fut Ind Pass P3 Sg => "agetur",
+        Perf Ind Pass P3 Sg N => "actum est",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_four("capio", "cepi", "captum", "capere");
+
+        Präs Ind Pass P1 Sg => "capior",
+        Präs Ind Pass P2 Sg => "caperis",
+        Präs Ind Pass P3 Pl => "capiuntur",
+        Impf Ind Pass P3 Sg => "capiebatur",
+        Fut Ind Pass P1 Sg => "capiar",
+        Fut Ind Pass P3 Sg => "capietur",
+        Plusq Ind Pass P3 Pl F => "captae erant",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_four("audio", "audivi", "auditum", "audire");
+
+        Präs Ind Pass P1 Sg => "audior",
+        Präs Ind Pass P2 Sg => "audiris",
+        Präs Ind Pass P3 Pl => "audiuntur",
+        Fut Ind Pass P2 Sg => "audieris",
+        Perf Ind Pass P1 Pl => "auditi sumus",
+    }
+}
+
+#[test]
+fn test_passiv_varianten() {
+    let verb = Verb::parse(&WörterbuchEintrag::from_four(
+        "laudo", "laudavi", "laudatum", "laudare",
+    ))
+    .unwrap();
+    assert_eq!(
+        verb.varianten(P2, Sg, Präs, Ind, Pass, M),
+        ["laudaris", "laudare"]
+    );
+    assert_eq!(
+        verb.varianten(P2, Sg, Fut, Ind, Pass, M),
+        ["laudaberis", "laudabere"]
+    );
+    assert_eq!(verb.varianten(P3, Pl, Perf, Ind, Pass, N), ["laudata sunt"]);
+    assert_eq!(verb.varianten(P2, Pl, Präs, Ind, Pass, M), ["laudamini"]);
+
+    let verb = Verb::parse(&WörterbuchEintrag::from_two("laudo", "laudare")).unwrap();
+    assert_eq!(
+        verb.konjugieren(P1, Sg, Präs, Ind, Pass, M).unwrap(),
+        "laudor"
+    );
+    assert_eq!(verb.konjugieren(P1, Sg, Perf, Ind, Pass, M), None);
+}
+
+#[test]
+fn test_konjunktiv() {
+    test_konjugation! {
+        WörterbuchEintrag::from_four("laudo", "laudavi", "laudatum", "laudare");
+
+        Präs Konj Akt P1 Sg => "laudem",
+        Präs Konj Akt P2 Sg => "laudes",
+        Präs Konj Akt P3 Sg => "laudet",
+        Präs Konj Akt P1 Pl => "laudemus",
+        Präs Konj Akt P2 Pl => "laudetis",
+        Präs Konj Akt P3 Pl => "laudent",
+
+        Impf Konj Akt P1 Sg => "laudarem",
+        Impf Konj Akt P3 Pl => "laudarent",
+
+        Perf Konj Akt P1 Sg => "laudaverim",
+        Perf Konj Akt P3 Pl => "laudaverint",
+
+        Plusq Konj Akt P1 Sg => "laudavissem",
+        Plusq Konj Akt P2 Pl => "laudavissetis",
+
+        Präs Konj Pass P1 Sg => "lauder",
+        Präs Konj Pass P2 Sg => "lauderis",
+        Präs Konj Pass P3 Pl => "laudentur",
+
+        Impf Konj Pass P1 Sg => "laudarer",
+        Impf Konj Pass P3 Sg => "laudaretur",
+
+        Perf Konj Pass P1 Sg => "laudatus sim",
+        Perf Konj Pass P3 Sg F => "laudata sit",
+        Perf Konj Pass P3 Pl N => "laudata sint",
+
+        Plusq Konj Pass P1 Sg => "laudatus essem",
+        Plusq Konj Pass P1 Pl F => "laudatae essemus",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_four("moneo", "monui", "monitum", "monere");
+
+        Präs Konj Akt P1 Sg => "moneam",
+        Präs Konj Akt P3 Pl => "moneant",
+        Impf Konj Akt P1 Sg => "monerem",
+        Präs Konj Pass P3 Sg => "moneatur",
+        Impf Konj Pass P3 Pl => "monerentur",
+        Perf Konj Akt P2 Sg => "monueris",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_four("ago", "egi", "actum", "agere");
+
+        Präs Konj Akt P1 Sg => "agam",
+        Präs Konj Akt P2 Sg => "agas",
+        Impf Konj Akt P1 Sg => "agerem",
+        Präs Konj Pass P1 Sg => "agar",
+        Präs Konj Pass P2 Sg => "agaris",
+        Plusq Konj Akt P3 Sg => "egisset",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_four("capio", "cepi", "captum", "capere");
+
+        Präs Konj Akt P1 Sg => "capiam",
+        Präs Konj Akt P3 Pl => "capiant",
+        Impf Konj Akt P1 Sg => "caperem",
+        Impf Konj Pass P3 Sg => "caperetur",
+        Perf Konj Pass P3 Sg N => "captum sit",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_four("audio", "audivi", "auditum", "audire");
+
+        Präs Konj Akt P1 Sg => "audiam",
+        Impf Konj Akt P1 Sg => "audirem",
+        Präs Konj Pass P1 Pl => "audiamur",
+        Impf Konj Pass P2 Pl => "audiremini",
+        Plusq Konj Akt P3 Pl => "audivissent",
+    }
+}
+
+#[test]
+fn test_konjunktiv_fehlende_formen() {
+    let verb = Verb::parse(&WörterbuchEintrag::from_four(
+        "laudo", "laudavi", "laudatum", "laudare",
+    ))
+    .unwrap();
+    for genus_verbi in [Akt, Pass] {
+        assert_eq!(verb.konjugieren(P1, Sg, Fut, Konj, genus_verbi, M), None);
+        assert_eq!(verb.konjugieren(P1, Sg, Fut2, Konj, genus_verbi, M), None);
+    }
+    assert_eq!(verb.varianten(P3, Pl, Perf, Konj, Akt, M), ["laudaverint"]);
+    assert_eq!(
+        verb.varianten(P2, Sg, Präs, Konj, Pass, M),
+        ["lauderis", "laudere"]
+    );
+}
+
+#[test]
+fn test_imperativ() {
+    test_konjugation! {
+        WörterbuchEintrag::from_four("laudo", "laudavi", "laudatum", "laudare");
+
+        Präs Imp Akt P2 Sg => "lauda",
+        Präs Imp Akt P2 Pl => "laudate",
+        Fut Imp Akt P2 Sg => "laudato",
+        Fut Imp Akt P3 Sg => "laudato",
+        Fut Imp Akt P2 Pl => "laudatote",
+        Fut Imp Akt P3 Pl => "laudanto",
+
+        Präs Imp Pass P2 Sg => "laudare",
+        Präs Imp Pass P2 Pl => "laudamini",
+        Fut Imp Pass P2 Sg => "laudator",
+        Fut Imp Pass P3 Pl => "laudantor",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_two("moneo", "monere");
+
+        Präs Imp Akt P2 Sg => "mone",
+        Präs Imp Akt P2 Pl => "monete",
+        Fut Imp Akt P3 Pl => "monento",
+        Präs Imp Pass P2 Sg => "monere",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_two("ago", "agere");
+
+        Präs Imp Akt P2 Sg => "age",
+        Präs Imp Akt P2 Pl => "agite",
+        Fut Imp Akt P2 Sg => "agito",
+        Fut Imp Akt P3 Pl => "agunto",
+        Präs Imp Pass P2 Pl => "agimini",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_two("capio", "capere");
+
+        Präs Imp Akt P2 Sg => "cape",
+        Präs Imp Akt P2 Pl => "capite",
+        Fut Imp Akt P3 Pl => "capiunto",
+        Fut Imp Pass P3 Pl => "capiuntor",
+    }
+    test_konjugation! {
+        WörterbuchEintrag::from_two("audio", "audire");
+
+        Präs Imp Akt P2 Sg => "audi",
+        Präs Imp Akt P2 Pl => "audite",
+        Fut Imp Akt P2 Pl => "auditote",
+        Präs Imp Pass P2 Sg => "audire",
+    }
+}
+
+#[test]
+fn test_imperativ_kurzformen() {
+    for (erste_form, infinitiv, singular, plural) in [
+        ("dico", "dicere", "dic", "dicite"),
+        ("duco", "ducere", "duc", "ducite"),
+        ("facio", "facere", "fac", "facite"),
+        ("fero", "ferre", "fer", "ferte"),
+        ("educo", "educere", "educ", "educite"),
+        ("conficio", "conficere", "confice", "conficite"),
+        ("praedico", "praedicare", "praedica", "praedicate"),
+    ] {
+        let verb = Verb::parse(&WörterbuchEintrag::from_two(erste_form, infinitiv)).unwrap();
+        assert_eq!(
+            verb.konjugieren(P2, Sg, Präs, Imp, Akt, M).unwrap(),
+            singular
+        );
+        assert_eq!(verb.konjugieren(P2, Pl, Präs, Imp, Akt, M).unwrap(), plural);
+    }
+}
+
+#[test]
+fn test_fero() {
+    test_konjugation! {
+        WörterbuchEintrag::from_four("fero", "tuli", "latum", "ferre");
+
+        Präs Ind Akt P1 Sg => "fero",
+        Präs Ind Akt P2 Sg => "fers",
+        Präs Ind Akt P3 Sg => "fert",
+        Präs Ind Akt P1 Pl => "ferimus",
+        Präs Ind Akt P2 Pl => "fertis",
+        Präs Ind Akt P3 Pl => "ferunt",
+        Präs Ind Pass P2 Sg => "ferris",
+        Präs Ind Pass P3 Sg => "fertur",
+        Fut Ind Akt P2 Sg => "feres",
+        Präs Konj Akt P1 Sg => "feram",
+        Impf Konj Akt P1 Sg => "ferrem",
+        Impf Konj Pass P3 Pl => "ferrentur",
+        Perf Ind Akt P1 Sg => "tuli",
+        Perf Ind Pass P3 Sg F => "lata est",
+
+        Präs Imp Akt P2 Sg => "fer",
+        Präs Imp Akt P2 Pl => "ferte",
+        Fut Imp Akt P3 Sg => "ferto",
+        Fut Imp Akt P2 Pl => "fertote",
+        Fut Imp Akt P3 Pl => "ferunto",
+        Präs Imp Pass P2 Sg => "ferre",
+        Präs Imp Pass P2 Pl => "ferimini",
+        Fut Imp Pass P2 Sg => "fertor",
+    }
+}
+
+#[test]
+fn test_imperativ_fehlende_formen() {
+    let verb = Verb::parse(&WörterbuchEintrag::from_four(
+        "laudo", "laudavi", "laudatum", "laudare",
+    ))
+    .unwrap();
+    for genus_verbi in [Akt, Pass] {
+        for numerus in [Sg, Pl] {
+            assert_eq!(
+                verb.konjugieren(P1, numerus, Präs, Imp, genus_verbi, M),
+                None
+            );
+            assert_eq!(
+                verb.konjugieren(P3, numerus, Präs, Imp, genus_verbi, M),
+                None
+            );
+            assert_eq!(
+                verb.konjugieren(P1, numerus, Fut, Imp, genus_verbi, M),
+                None
+            );
+        }
+        for tempus in [Impf, Perf, Plusq, Fut2] {
+            assert_eq!(verb.konjugieren(P2, Sg, tempus, Imp, genus_verbi, M), None);
+        }
+    }
+    assert_eq!(verb.konjugieren(P2, Pl, Fut, Imp, Pass, M), None);
+    assert!(verb.varianten(P1, Sg, Präs, Imp, Akt, M).is_empty());
+}
+
+#[test]
+fn test_klassifikation() {
+    use Konjugationsklasse as K;
+
+    for (erste_form, infinitiv, klasse, stamm) in [
+        ("laudo", "laudare", K::AKonjugation, "laud"),
+        ("creo", "creare", K::AKonjugation, "cre"),
+        ("moneo", "monere", K::EKonjugation, "mon"),
+        ("ago", "agere", K::KonsonantischeKonjugation, "ag"),
+        ("capio", "capere", K::KurzvokalischeIKonjugation, "cap"),
+        ("audio", "audire", K::IKonjugation, "aud"),
+    ] {
+        let verb = Verb::parse(&WörterbuchEintrag::from_two(erste_form, infinitiv)).unwrap();
+        let klassifikation = verb.get_klassifikation();
+        assert_eq!(klassifikation.get_klasse(), klasse, "{erste_form}");
+        assert_eq!(klassifikation.get_stamm(), stamm, "{erste_form}");
+    }
+}
+
+#[test]
+fn test_ablehnung() {
+    let Err(ParseFehler::KeineKonjugation(ablehnungen)) =
+        Verb::parse(&WörterbuchEintrag::from_two("laudo", "laudire"))
+    else {
+        panic!("laudo, laudire should not parse");
+    };
+    assert_eq!(ablehnungen.len(), 5);
+    assert_eq!(
+        ablehnungen[0].get_grund(),
+        Ablehnungsgrund::InfinitivPasstNicht
+    );
+    assert_eq!(
+        ablehnungen[1].get_grund(),
+        Ablehnungsgrund::ErsteFormPasstNicht
+    );
+
+    assert!(Verb::parse(&WörterbuchEintrag::from_two("rosa", "rosare")).is_err());
+
+    let fehler = Verb::parse(&WörterbuchEintrag::from_two("laudo", "laudire"))
+        .err()
+        .unwrap();
+    assert!(fehler
+        .to_string()
+        .starts_with("keine passende Konjugation: a-Konjugation (Infinitiv passt nicht), "));
+}
+
+#[test]
+fn test_ablehnung_stammformen() {
+    for (eintrag, grund) in [
+        (
+            WörterbuchEintrag::from_four("laudo", "monui", "laudatum", "laudare"),
+            Ablehnungsgrund::PerfektPasstNicht,
+        ),
+        (
+            WörterbuchEintrag::from_four("laudo", "laudavit", "laudatum", "laudare"),
+            Ablehnungsgrund::PerfektPasstNicht,
+        ),
+        (
+            WörterbuchEintrag::from_four("laudo", "laudavi", "monitum", "laudare"),
+            Ablehnungsgrund::SupinumPasstNicht,
+        ),
+        (
+            WörterbuchEintrag::from_four("laudo", "laudavi", "laudatus", "laudare"),
+            Ablehnungsgrund::SupinumPasstNicht,
+        ),
+    ] {
+        let Err(ParseFehler::KeineKonjugation(ablehnungen)) = Verb::parse(&eintrag) else {
+            panic!("{eintrag:?} should not parse");
+        };
+        assert_eq!(ablehnungen[0].get_grund(), grund, "{eintrag:?}");
+        assert_eq!(
+            ablehnungen[0].to_string(),
+            format!("a-Konjugation ({grund})")
+        );
+    }
+}
+
+#[test]
+fn test_stammformen() {
+    for (erste_form, perfekt, supinum, infinitiv) in [
+        ("do", "dedi", "datum", "dare"),
+        ("sto", "steti", "statum", "stare"),
+        ("lavo", "lavi", "lautum", "lavare"),
+        ("video", "vidi", "visum", "videre"),
+        ("maneo", "mansi", "mansum", "manere"),
+        ("moveo", "movi", "motum", "movere"),
+        ("spondeo", "spopondi", "sponsum", "spondere"),
+        ("possideo", "possedi", "possessum", "possidere"),
+        ("impleo", "implevi", "impletum", "implere"),
+        ("constituo", "constitui", "constitutum", "constituere"),
+        ("credo", "credidi", "creditum", "credere"),
+        ("tego", "texi", "tectum", "tegere"),
+        ("iungo", "iunxi", "iunctum", "iungere"),
+        ("adduco", "adduxi", "adductum", "adducere"),
+        ("scribo", "scripsi", "scriptum", "scribere"),
+        ("mitto", "misi", "missum", "mittere"),
+        ("ostendo", "ostendi", "ostentum", "ostendere"),
+        ("vinco", "vici", "victum", "vincere"),
+        ("cano", "cecini", "cantum", "canere"),
+        ("cado", "cecidi", "casum", "cadere"),
+        ("curro", "cucurri", "cursum", "currere"),
+        ("tango", "tetigi", "tactum", "tangere"),
+        ("diligo", "dilexi", "dilectum", "diligere"),
+        ("intellego", "intellexi", "intellectum", "intellegere"),
+        ("colligo", "collegi", "collectum", "colligere"),
+        ("dirigo", "direxi", "directum", "dirigere"),
+        ("pasco", "pavi", "pastum", "pascere"),
+        ("accipio", "accepi", "acceptum", "accipere"),
+        ("eripio", "eripui", "ereptum", "eripere"),
+        ("haurio", "hausi", "haustum", "haurire"),
+        ("invenio", "inveni", "inventum", "invenire"),
+        // Unregelmäßige Stammformen und ihre Komposita
+        ("cogo", "coegi", "coactum", "cogere"),
+        ("pello", "pepuli", "pulsum", "pellere"),
+        ("tollo", "sustuli", "sublatum", "tollere"),
+        ("perfero", "pertuli", "perlatum", "perferre"),
+        ("affero", "attuli", "allatum", "afferre"),
+        ("refero", "rettuli", "relatum", "referre"),
+    ] {
+        let eintrag = WörterbuchEintrag::from_four(erste_form, perfekt, supinum, infinitiv);
+        let verb = Verb::parse(&eintrag).unwrap_or_else(|fehler| panic!("{eintrag:?}: {fehler}"));
+        assert_eq!(
+            verb.konjugieren(P1, Sg, Perf, Ind, Akt, M).as_deref(),
+            Some(perfekt),
+            "{eintrag:?}"
+        );
+    }
+}
+
+#[test]
+fn test_ablehnung_fremde_stammformen() {
+    for (eintrag, klasse, grund) in [
+        (
+            WörterbuchEintrag::from_four("laudo", "lusi", "lusum", "laudare"),
+            Konjugationsklasse::AKonjugation,
+            Ablehnungsgrund::PerfektPasstNicht,
+        ),
+        (
+            WörterbuchEintrag::from_four("fero", "xyzi", "qqum", "ferre"),
+            Konjugationsklasse::KonsonantischeKonjugation,
+            Ablehnungsgrund::PerfektPasstNicht,
+        ),
+        (
+            WörterbuchEintrag::from_four("fero", "tuli", "qqum", "ferre"),
+            Konjugationsklasse::KonsonantischeKonjugation,
+            Ablehnungsgrund::SupinumPasstNicht,
+        ),
+        (
+            WörterbuchEintrag::from_four("ago", "cepi", "actum", "agere"),
+            Konjugationsklasse::KonsonantischeKonjugation,
+            Ablehnungsgrund::PerfektPasstNicht,
+        ),
+        (
+            WörterbuchEintrag::from_four("moneo", "monui", "monatum", "monere"),
+            Konjugationsklasse::EKonjugation,
+            Ablehnungsgrund::SupinumPasstNicht,
+        ),
+        (
+            WörterbuchEintrag::from_four("tego", "texi", "tessum", "tegere"),
+            Konjugationsklasse::KonsonantischeKonjugation,
+            Ablehnungsgrund::SupinumPasstNicht,
+        ),
+        (
+            WörterbuchEintrag::from_four("affero", "aptuli", "allatum", "afferre"),
+            Konjugationsklasse::KonsonantischeKonjugation,
+            Ablehnungsgrund::PerfektPasstNicht,
+        ),
+    ] {
+        let Err(ParseFehler::KeineKonjugation(ablehnungen)) = Verb::parse(&eintrag) else {
+            panic!("{eintrag:?} should not parse");
+        };
+        let ablehnung = ablehnungen.iter().max_by_key(|a| a.get_grund()).unwrap();
+        assert_eq!(ablehnung.get_grund(), grund, "{eintrag:?}");
+        assert!(ablehnung.to_string().starts_with(klasse.get_name()));
+    }
+}
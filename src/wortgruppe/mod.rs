@@ -0,0 +1,63 @@
+use crate::{
+    adjektiv::Adjektiv,
+    grammatik::{Kasus, Numerus},
+    nomen::Nomen,
+};
+
+#[cfg(test)]
+mod tests;
+
+// Ein Nomen mit Adjektiven, die in Genus, Numerus und Kasus mit ihm übereinstimmen. Die
+// Wortstellung des Eintrags bleibt erhalten: res publica, mare nostrum, magna voce
+pub struct Wortgruppe<'a> {
+    vorangestellt: Vec<Adjektiv<'a>>,
+    nomen: Nomen<'a>,
+    nachgestellt: Vec<Adjektiv<'a>>,
+}
+
+impl<'a> Wortgruppe<'a> {
+    pub fn new(nomen: Nomen<'a>) -> Self {
+        Self {
+            vorangestellt: Vec::new(),
+            nomen,
+            nachgestellt: Vec::new(),
+        }
+    }
+
+    // Fügt ein Adjektiv direkt vor dem Nomen ein
+    pub fn vorangestellt(mut self, adjektiv: Adjektiv<'a>) -> Self {
+        self.vorangestellt.push(adjektiv);
+        self
+    }
+
+    // Fügt ein Adjektiv nach dem Nomen und den bisher nachgestellten Adjektiven ein
+    pub fn nachgestellt(mut self, adjektiv: Adjektiv<'a>) -> Self {
+        self.nachgestellt.push(adjektiv);
+        self
+    }
+
+    pub fn get_nomen(&self) -> &Nomen<'a> {
+        &self.nomen
+    }
+
+    // Bei Pluralwörtern (castra, arma) steht die Wortgruppe immer im Plural
+    pub fn deklinieren(&self, numerus: Numerus, kasus: Kasus) -> Option<String> {
+        let numerus = if self.nomen.get_klassifikation().is_plural() {
+            Numerus::Plural
+        } else {
+            numerus
+        };
+        let genus = self.nomen.get_genus();
+
+        let mut wörter = Vec::with_capacity(self.vorangestellt.len() + 1 + self.nachgestellt.len());
+        for adjektiv in &self.vorangestellt {
+            wörter.push(adjektiv.deklinieren(genus, numerus, kasus)?);
+        }
+        wörter.push(self.nomen.deklinieren(numerus, kasus)?);
+        for adjektiv in &self.nachgestellt {
+            wörter.push(adjektiv.deklinieren(genus, numerus, kasus)?);
+        }
+
+        Some(wörter.join(" "))
+    }
+}
@@ -0,0 +1,85 @@
+use super::Wortgruppe;
+use crate::{
+    adjektiv::{self, Adjektiv},
+    grammatik::{
+        Genus::{self, Femininum as F, Maskulinum as M, Neutrum as N},
+        Kasus::{
+            Ablativ as Abl, Akkusativ as Akk, Dativ as Dat, Genitiv as Gen, Nominativ as Nom,
+            Vokativ as Vok,
+        },
+        Numerus::{Plural as Pl, Singular as Sg},
+    },
+    nomen::{self, Nomen},
+};
+
+fn nomen<'a>(nominativ: &'a str, genitiv: &'a str, genus: Genus) -> Nomen<'a> {
+    Nomen::parse(&nomen::WörterbuchEintrag {
+        nominativ,
+        genitiv: Some(genitiv),
+        genus: Some(genus),
+        ortsname: false,
+    })
+    .unwrap()
+}
+
+fn adjektiv<'a>(erste_form: &'a str, zweite_form: &'a str, dritte_form: &'a str) -> Adjektiv<'a> {
+    Adjektiv::parse(&adjektiv::WörterbuchEintrag::from_three(
+        erste_form,
+        zweite_form,
+        dritte_form,
+    ))
+    .unwrap()
+}
+
+#[test]
+fn test_res_publica() {
+    let wortgruppe = Wortgruppe::new(nomen("res", "rei", F))
+        .nachgestellt(adjektiv("publicus", "publica", "publicum"));
+    assert_eq!(wortgruppe.deklinieren(Sg, Nom).unwrap(), "res publica");
+    assert_eq!(wortgruppe.deklinieren(Sg, Gen).unwrap(), "rei publicae");
+    assert_eq!(wortgruppe.deklinieren(Sg, Akk).unwrap(), "rem publicam");
+    assert_eq!(wortgruppe.deklinieren(Sg, Abl).unwrap(), "re publica");
+    assert_eq!(wortgruppe.deklinieren(Pl, Gen).unwrap(), "rerum publicarum");
+}
+
+#[test]
+fn test_vir_bonus() {
+    let wortgruppe =
+        Wortgruppe::new(nomen("vir", "viri", M)).nachgestellt(adjektiv("bonus", "bona", "bonum"));
+    assert_eq!(wortgruppe.deklinieren(Sg, Vok).unwrap(), "vir bone");
+    assert_eq!(wortgruppe.deklinieren(Sg, Dat).unwrap(), "viro bono");
+    assert_eq!(wortgruppe.deklinieren(Pl, Akk).unwrap(), "viros bonos");
+}
+
+#[test]
+fn test_mare_nostrum() {
+    let wortgruppe = Wortgruppe::new(nomen("mare", "maris", N))
+        .nachgestellt(adjektiv("noster", "nostra", "nostrum"));
+    assert_eq!(wortgruppe.deklinieren(Sg, Nom).unwrap(), "mare nostrum");
+    assert_eq!(wortgruppe.deklinieren(Sg, Abl).unwrap(), "mari nostro");
+    assert_eq!(wortgruppe.deklinieren(Pl, Nom).unwrap(), "maria nostra");
+}
+
+#[test]
+fn test_wortstellung() {
+    let wortgruppe = Wortgruppe::new(nomen("vox", "vocis", F))
+        .vorangestellt(adjektiv("magnus", "magna", "magnum"))
+        .nachgestellt(adjektiv("clarus", "clara", "clarum"));
+    assert_eq!(wortgruppe.deklinieren(Sg, Abl).unwrap(), "magna voce clara");
+    assert_eq!(
+        wortgruppe.deklinieren(Pl, Gen).unwrap(),
+        "magnarum vocum clararum"
+    );
+}
+
+#[test]
+fn test_pluralwort() {
+    let wortgruppe = Wortgruppe::new(nomen("castra", "castrorum", N))
+        .nachgestellt(adjektiv("novus", "nova", "novum"));
+    assert_eq!(wortgruppe.deklinieren(Sg, Nom).unwrap(), "castra nova");
+    assert_eq!(wortgruppe.deklinieren(Sg, Abl).unwrap(), "castris novis");
+    assert_eq!(
+        wortgruppe.deklinieren(Pl, Gen).unwrap(),
+        "castrorum novorum"
+    );
+}